@@ -0,0 +1,5 @@
+/// Analytics module - records executed opportunities for post-hoc tuning
+
+pub mod recorder;
+
+pub use recorder::{MinuteBucket, OpportunityRecord, OpportunityRecorder};
@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+
+use crate::strategy::OrderSide;
+
+/// A single evaluated-and-taken opportunity, ready to be appended to the
+/// rolling sink
+#[derive(Debug, Clone)]
+pub struct OpportunityRecord {
+    pub direction: OrderSide,
+    pub pacifica_price: f64,
+    pub hyperliquid_price: f64,
+    pub size: f64,
+    pub initial_profit_bps: f64,
+    pub realized_profit_bps: f64,
+    /// When the opportunity was evaluated, in epoch milliseconds
+    pub opened_at_ms: u64,
+    /// When the cycle closed (fully hedged), in epoch milliseconds
+    pub closed_at_ms: u64,
+}
+
+impl OpportunityRecord {
+    /// Serialize as a single newline-delimited JSON line
+    fn to_json_line(&self) -> String {
+        format!(
+            "{{\"direction\":\"{}\",\"pacifica_price\":{},\"hyperliquid_price\":{},\"size\":{},\
+             \"initial_profit_bps\":{},\"realized_profit_bps\":{},\"opened_at_ms\":{},\"closed_at_ms\":{}}}",
+            self.direction.as_str(),
+            self.pacifica_price,
+            self.hyperliquid_price,
+            self.size,
+            self.initial_profit_bps,
+            self.realized_profit_bps,
+            self.opened_at_ms,
+            self.closed_at_ms,
+        )
+    }
+
+    fn filled_notional(&self) -> f64 {
+        self.size * self.pacifica_price
+    }
+}
+
+/// Aggregated stats for one minute of trading activity
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinuteBucket {
+    pub count: u64,
+    pub filled_notional: f64,
+    pub expected_profit_bps_sum: f64,
+    pub realized_profit_bps_sum: f64,
+    pub wins: u64,
+}
+
+impl MinuteBucket {
+    fn record(&mut self, rec: &OpportunityRecord) {
+        self.count += 1;
+        self.filled_notional += rec.filled_notional();
+        self.expected_profit_bps_sum += rec.initial_profit_bps;
+        self.realized_profit_bps_sum += rec.realized_profit_bps;
+        if rec.realized_profit_bps > 0.0 {
+            self.wins += 1;
+        }
+    }
+
+    /// Average realized profit in bps across the bucket's cycles
+    pub fn avg_realized_profit_bps(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.realized_profit_bps_sum / self.count as f64
+        }
+    }
+
+    /// Fraction of cycles in the bucket that closed with a positive realized profit
+    pub fn win_rate(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.count as f64
+        }
+    }
+}
+
+/// Appends completed `Opportunity` cycles to a rolling file sink and keeps a
+/// per-minute rollup in memory so operators can tune `profit_rate_bps`
+/// against actual captured edge instead of guesses
+pub struct OpportunityRecorder {
+    sink: Mutex<File>,
+    buckets: Mutex<BTreeMap<u64, MinuteBucket>>,
+}
+
+impl OpportunityRecorder {
+    /// Open (or create) the rolling record file at `path`, appending to it
+    pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path.as_ref())
+            .with_context(|| format!("Failed to open opportunity log at {}", path.as_ref().display()))?;
+
+        Ok(Self {
+            sink: Mutex::new(file),
+            buckets: Mutex::new(BTreeMap::new()),
+        })
+    }
+
+    /// Record a closed-cycle opportunity: append it to the file sink and
+    /// fold it into its per-minute bucket
+    pub fn record_closed_cycle(&self, record: &OpportunityRecord) -> Result<()> {
+        {
+            let mut sink = self.sink.lock().unwrap();
+            writeln!(sink, "{}", record.to_json_line()).context("Failed to append opportunity record")?;
+        }
+
+        let minute_bucket_key = record.closed_at_ms / 60_000;
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets.entry(minute_bucket_key).or_default().record(record);
+
+        Ok(())
+    }
+
+    /// Snapshot of all per-minute buckets collected so far, keyed by minute
+    /// (epoch milliseconds / 60000)
+    pub fn minute_buckets(&self) -> BTreeMap<u64, MinuteBucket> {
+        self.buckets.lock().unwrap().clone()
+    }
+}
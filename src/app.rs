@@ -1,14 +1,21 @@
 use anyhow::{Context, Result};
+use parking_lot::Mutex;
 use std::collections::HashSet;
 use std::sync::Arc;
-use std::sync::Mutex;
 use std::time::Instant;
 use tokio::sync::{mpsc, RwLock};
 
+use crate::bot::risk::RiskBudget;
 use crate::bot::BotState;
 use crate::config::Config;
 use crate::connector::hyperliquid::{HyperliquidCredentials, HyperliquidTrading};
 use crate::connector::pacifica::{PacificaCredentials, PacificaTrading, PacificaWsTrading};
+use crate::metrics::Metrics;
+use crate::services::hedge_feed::HedgeFeedPublisher;
+use crate::services::order_monitor::SharedOrderSnapshot;
+use crate::services::orderbook::TimestampedPrice;
+use crate::services::state_feed::StatePublisher;
+use crate::storage::Storage;
 use crate::strategy::{OpportunityEvaluator, OrderSide};
 
 /// Position snapshot for tracking position deltas
@@ -19,6 +26,140 @@ pub struct PositionSnapshot {
     pub last_check: Instant,
 }
 
+/// Trading clients and durable storage shared across every symbol a
+/// `Supervisor` runs. These are plain REST/WS clients scoped to the account's
+/// credentials rather than to a single symbol, so connecting one set and
+/// reusing it for every `XemmBot` avoids opening redundant connection pools
+/// per pair. Cheap to clone: every field is either an `Arc` or (for the
+/// credentials) already `Clone`.
+#[derive(Clone)]
+pub struct SharedExchangeClients {
+    pub pacifica_trading_main: Arc<PacificaTrading>,
+    pub pacifica_trading_fill: Arc<PacificaTrading>,
+    pub pacifica_trading_rest_fill: Arc<PacificaTrading>,
+    pub pacifica_trading_monitor: Arc<PacificaTrading>,
+    pub pacifica_trading_hedge: Arc<PacificaTrading>,
+    pub pacifica_trading_rest_poll: Arc<PacificaTrading>,
+    pub pacifica_ws_trading: Arc<PacificaWsTrading>,
+    pub hyperliquid_trading: Arc<HyperliquidTrading>,
+    pub pacifica_credentials: PacificaCredentials,
+    pub metrics: Arc<Metrics>,
+    pub store: Arc<Storage>,
+
+    // Dashboard fan-out, shared across every symbol the same way `metrics`/`store`
+    // are - one `/state` and one `/hedges` listener for the whole portfolio,
+    // not one per symbol
+    pub state_publisher: Arc<StatePublisher>,
+    pub hedge_feed: Arc<HedgeFeedPublisher>,
+}
+
+impl SharedExchangeClients {
+    /// Load credentials from the environment, connect one set of trading
+    /// clients, and open durable storage - all of which are safe to share
+    /// across every symbol a `Supervisor` runs
+    pub async fn connect(state_db_path: &str) -> Result<Self> {
+        use colored::Colorize;
+
+        dotenv::dotenv().ok();
+        let pacifica_credentials =
+            PacificaCredentials::from_env().context("Failed to load Pacifica credentials from environment")?;
+        let hyperliquid_credentials =
+            HyperliquidCredentials::from_env().context("Failed to load Hyperliquid credentials from environment")?;
+
+        println!(
+            "{} {} {}",
+            chrono::Utc::now()
+                .format("%Y-%m-%dT%H:%M:%S%.6fZ")
+                .to_string()
+                .bright_black(),
+            "[INIT]".cyan().bold(),
+            "Credentials loaded successfully".green()
+        );
+
+        let pacifica_trading_main = Arc::new(
+            PacificaTrading::new(pacifica_credentials.clone())
+                .context("Failed to create main Pacifica trading client")?,
+        );
+        let pacifica_trading_fill = Arc::new(
+            PacificaTrading::new(pacifica_credentials.clone())
+                .context("Failed to create fill detection Pacifica trading client")?,
+        );
+        let pacifica_trading_rest_fill = Arc::new(
+            PacificaTrading::new(pacifica_credentials.clone())
+                .context("Failed to create REST fill detection Pacifica trading client")?,
+        );
+        let pacifica_trading_monitor = Arc::new(
+            PacificaTrading::new(pacifica_credentials.clone())
+                .context("Failed to create monitor Pacifica trading client")?,
+        );
+        let pacifica_trading_hedge = Arc::new(
+            PacificaTrading::new(pacifica_credentials.clone())
+                .context("Failed to create hedge Pacifica trading client")?,
+        );
+        let pacifica_trading_rest_poll = Arc::new(
+            PacificaTrading::new(pacifica_credentials.clone())
+                .context("Failed to create REST polling Pacifica trading client")?,
+        );
+
+        // Initialize WebSocket trading client for ultra-fast cancellations
+        let pacifica_ws_trading = Arc::new(PacificaWsTrading::new(pacifica_credentials.clone(), false)); // false = mainnet
+
+        let hyperliquid_trading = Arc::new(
+            HyperliquidTrading::new(hyperliquid_credentials, false)
+                .context("Failed to create Hyperliquid trading client")?,
+        );
+
+        println!(
+            "{} {} {}",
+            chrono::Utc::now()
+                .format("%Y-%m-%dT%H:%M:%S%.6fZ")
+                .to_string()
+                .bright_black(),
+            "[INIT]".cyan().bold(),
+            "Trading clients initialized (6 REST instances + WebSocket)".green()
+        );
+
+        hyperliquid_trading
+            .get_meta()
+            .await
+            .context("Failed to pre-fetch Hyperliquid metadata")?;
+        println!(
+            "{} {} {} Hyperliquid metadata cached",
+            chrono::Utc::now()
+                .format("%Y-%m-%dT%H:%M:%S%.6fZ")
+                .to_string()
+                .bright_black(),
+            "[INIT]".cyan().bold(),
+            "✓".green().bold()
+        );
+
+        let metrics = Metrics::new();
+        let store = Arc::new(Storage::new(state_db_path).context("Failed to open durable storage")?);
+
+        // Capacity chosen the same way `OrderMonitorService::new`'s cancel
+        // channel is (64): generous enough that a momentarily slow dashboard
+        // client lags instead of forcing every other subscriber to miss messages
+        let state_publisher = Arc::new(StatePublisher::new(256));
+        let hedge_feed = Arc::new(HedgeFeedPublisher::new(256));
+
+        Ok(Self {
+            pacifica_trading_main,
+            pacifica_trading_fill,
+            pacifica_trading_rest_fill,
+            pacifica_trading_monitor,
+            pacifica_trading_hedge,
+            pacifica_trading_rest_poll,
+            pacifica_ws_trading,
+            hyperliquid_trading,
+            pacifica_credentials,
+            metrics,
+            store,
+            state_publisher,
+            hedge_feed,
+        })
+    }
+}
+
 /// XemmBot - Main application structure that encapsulates all bot components
 pub struct XemmBot {
     pub config: Config,
@@ -34,39 +175,71 @@ pub struct XemmBot {
     pub pacifica_ws_trading: Arc<PacificaWsTrading>,
     pub hyperliquid_trading: Arc<HyperliquidTrading>,
 
-    // Shared state (prices)
-    pub pacifica_prices: Arc<Mutex<(f64, f64)>>, // (bid, ask)
-    pub hyperliquid_prices: Arc<Mutex<(f64, f64)>>, // (bid, ask)
+    // Shared state (prices), timestamped so a stalled venue can be detected
+    pub pacifica_prices: Arc<Mutex<TimestampedPrice>>,
+    pub hyperliquid_prices: Arc<Mutex<TimestampedPrice>>,
 
     // Opportunity evaluator
     pub evaluator: OpportunityEvaluator,
 
+    // Portfolio-wide gross notional / net delta budget, checked by the order
+    // placement service before this symbol places an order; unconstrained for
+    // a standalone single-symbol bot, shared across symbols under a `Supervisor`
+    pub risk_budget: Arc<RiskBudget>,
+
+    // Observability: latency histograms + counters, scraped over HTTP by Prometheus
+    pub metrics: Arc<Metrics>,
+
+    // Durable fill/hedge/PnL persistence, rehydrated into processed_fills and
+    // last_position_snapshot on startup so a restart doesn't re-hedge a fill
+    // it already processed
+    pub store: Arc<Storage>,
+
     // Fill tracking state
     pub processed_fills: Arc<tokio::sync::Mutex<HashSet<String>>>,
     pub last_position_snapshot: Arc<tokio::sync::Mutex<Option<PositionSnapshot>>>,
 
     // Channels
-    pub hedge_tx: mpsc::Sender<(OrderSide, f64, f64)>,
-    pub hedge_rx: Option<mpsc::Receiver<(OrderSide, f64, f64)>>,
+    // Hedge message carries the captured Pacifica/Hyperliquid price epochs
+    // alongside (side, size, avg_price). `run()` does not consume this
+    // channel: the `HedgeService` it spawns receives fills, with the same
+    // epochs, over its own `fill_tx` channel, re-checks
+    // `check_hedge_epoch_freshness` against the live quote right before
+    // submitting the taker order, and on a stale view logs a warning and
+    // submits anyway rather than aborting.
+    pub hedge_tx: mpsc::Sender<(OrderSide, f64, f64, u64, u64)>,
+    pub hedge_rx: Option<mpsc::Receiver<(OrderSide, f64, f64, u64, u64)>>,
     pub shutdown_tx: mpsc::Sender<()>,
     pub shutdown_rx: Option<mpsc::Receiver<()>>,
 
     // Credentials (needed for spawning services)
     pub pacifica_credentials: PacificaCredentials,
+
+    // Dashboard fan-out, shared across every symbol under a `Supervisor` -
+    // see `SharedExchangeClients::state_publisher`/`hedge_feed`
+    pub state_publisher: Arc<StatePublisher>,
+    pub hedge_feed: Arc<HedgeFeedPublisher>,
+
+    // Whether this instance's `run()` should bind the shared Prometheus endpoint
+    // (and the shared `/state`/`/hedges` dashboard listeners). A `Supervisor`
+    // shares these across every symbol, so only one of its bots should
+    // actually bind the listeners.
+    pub serves_metrics: bool,
 }
 
 impl XemmBot {
-    /// Create and initialize a new XemmBot instance
+    /// Create and initialize a standalone, single-symbol XemmBot instance
     ///
     /// This performs all the wiring:
     /// - Loads config and validates it
-    /// - Loads credentials from environment
-    /// - Creates all trading clients
-    /// - Pre-fetches Hyperliquid metadata
+    /// - Connects trading clients via `SharedExchangeClients::connect`
     /// - Cancels existing orders
     /// - Fetches Pacifica tick size
     /// - Creates OpportunityEvaluator
     /// - Initializes shared state and channels
+    ///
+    /// A `Supervisor` running multiple symbols skips this in favor of
+    /// `new_with_shared_clients`, so the connection setup above only happens once.
     pub async fn new() -> Result<Self> {
         use colored::Colorize;
 
@@ -187,92 +360,67 @@ impl XemmBot {
             "[CONFIG]".blue().bold(),
             format!("{}%", config.hyperliquid_slippage * 100.0).bright_white()
         );
-        println!();
-
-        // Load credentials
-        dotenv::dotenv().ok();
-        let pacifica_credentials =
-            PacificaCredentials::from_env().context("Failed to load Pacifica credentials from environment")?;
-        let hyperliquid_credentials =
-            HyperliquidCredentials::from_env().context("Failed to load Hyperliquid credentials from environment")?;
-
         println!(
-            "{} {} {}",
+            "{} {} Max Hedge Retries: {}",
             chrono::Utc::now()
                 .format("%Y-%m-%dT%H:%M:%S%.6fZ")
                 .to_string()
                 .bright_black(),
-            "[INIT]".cyan().bold(),
-            "Credentials loaded successfully".green()
-        );
-
-        // Initialize trading clients
-        let pacifica_trading_main = Arc::new(
-            PacificaTrading::new(pacifica_credentials.clone())
-                .context("Failed to create main Pacifica trading client")?,
-        );
-        let pacifica_trading_fill = Arc::new(
-            PacificaTrading::new(pacifica_credentials.clone())
-                .context("Failed to create fill detection Pacifica trading client")?,
-        );
-        let pacifica_trading_rest_fill = Arc::new(
-            PacificaTrading::new(pacifica_credentials.clone())
-                .context("Failed to create REST fill detection Pacifica trading client")?,
-        );
-        let pacifica_trading_monitor = Arc::new(
-            PacificaTrading::new(pacifica_credentials.clone())
-                .context("Failed to create monitor Pacifica trading client")?,
-        );
-        let pacifica_trading_hedge = Arc::new(
-            PacificaTrading::new(pacifica_credentials.clone())
-                .context("Failed to create hedge Pacifica trading client")?,
-        );
-        let pacifica_trading_rest_poll = Arc::new(
-            PacificaTrading::new(pacifica_credentials.clone())
-                .context("Failed to create REST polling Pacifica trading client")?,
-        );
-
-        // Initialize WebSocket trading client for ultra-fast cancellations
-        let pacifica_ws_trading = Arc::new(PacificaWsTrading::new(pacifica_credentials.clone(), false)); // false = mainnet
-
-        let hyperliquid_trading = Arc::new(
-            HyperliquidTrading::new(hyperliquid_credentials, false)
-                .context("Failed to create Hyperliquid trading client")?,
+            "[CONFIG]".blue().bold(),
+            format!("{} (then unwind on Pacifica)", config.max_hedge_retries).bright_white()
         );
-
         println!(
-            "{} {} {}",
+            "{} {} Funding Carry Adjustment: {}",
             chrono::Utc::now()
                 .format("%Y-%m-%dT%H:%M:%S%.6fZ")
                 .to_string()
                 .bright_black(),
-            "[INIT]".cyan().bold(),
-            "Trading clients initialized (6 REST instances + WebSocket)".green()
+            "[CONFIG]".blue().bold(),
+            if config.funding_adjustment_enabled {
+                format!("enabled, {} interval(s) holding horizon", config.funding_holding_intervals).green()
+            } else {
+                "disabled".yellow()
+            }
         );
+        println!();
 
-        // Pre-fetch Hyperliquid metadata (szDecimals, etc.) to reduce hedge latency
-        println!(
-            "{} {} Pre-fetching Hyperliquid metadata for {}...",
-            chrono::Utc::now()
-                .format("%Y-%m-%dT%H:%M:%S%.6fZ")
-                .to_string()
-                .bright_black(),
-            "[INIT]".cyan().bold(),
-            config.symbol.bright_white()
-        );
-        hyperliquid_trading
-            .get_meta()
-            .await
-            .context("Failed to pre-fetch Hyperliquid metadata")?;
-        println!(
-            "{} {} {} Hyperliquid metadata cached",
-            chrono::Utc::now()
-                .format("%Y-%m-%dT%H:%M:%S%.6fZ")
-                .to_string()
-                .bright_black(),
-            "[INIT]".cyan().bold(),
-            "✓".green().bold()
-        );
+        // Connect one set of trading clients, shared with every other symbol if
+        // this bot is ultimately run under a `Supervisor`
+        let shared = SharedExchangeClients::connect(&config.state_db_path).await?;
+        let symbol = config.symbol.clone();
+        let risk_budget = Arc::new(RiskBudget::unconstrained());
+
+        Self::new_with_shared_clients(config, symbol, shared, risk_budget).await
+    }
+
+    /// Build a single symbol's `XemmBot` from an already-connected
+    /// `SharedExchangeClients` bundle and portfolio risk budget - the entry
+    /// point `Supervisor` uses to wire up each symbol without reconnecting
+    pub async fn new_with_shared_clients(
+        mut config: Config,
+        symbol: String,
+        shared: SharedExchangeClients,
+        risk_budget: Arc<RiskBudget>,
+    ) -> Result<Self> {
+        use colored::Colorize;
+
+        config.symbol = symbol;
+
+        let SharedExchangeClients {
+            pacifica_trading_main,
+            pacifica_trading_fill,
+            pacifica_trading_rest_fill,
+            pacifica_trading_monitor,
+            pacifica_trading_hedge,
+            pacifica_trading_rest_poll,
+            pacifica_ws_trading,
+            hyperliquid_trading,
+            pacifica_credentials,
+            metrics,
+            store,
+            state_publisher,
+            hedge_feed,
+        } = shared;
 
         // Cancel any existing orders on Pacifica at startup
         println!(
@@ -338,6 +486,10 @@ impl XemmBot {
             config.hyperliquid_taker_fee_bps,
             config.profit_rate_bps,
             pacifica_tick_size,
+            0.0, // Pacifica funding rate; nothing in this crate calls set_funding_rates, so it stays 0.0
+            0.0, // Hyperliquid funding rate; nothing in this crate calls set_funding_rates, so it stays 0.0
+            config.funding_holding_intervals,
+            config.funding_adjustment_enabled,
         );
 
         println!(
@@ -351,19 +503,44 @@ impl XemmBot {
         );
 
         // Shared state for orderbook prices
-        let pacifica_prices = Arc::new(Mutex::new((0.0, 0.0))); // (bid, ask)
-        let hyperliquid_prices = Arc::new(Mutex::new((0.0, 0.0))); // (bid, ask)
+        let pacifica_prices = Arc::new(Mutex::new(TimestampedPrice::new()));
+        let hyperliquid_prices = Arc::new(Mutex::new(TimestampedPrice::new()));
 
         // Shared bot state
         let bot_state = Arc::new(RwLock::new(BotState::new()));
 
+        // `metrics` and `store` came in via `shared` - both are safe to reuse
+        // across every symbol, so they aren't recreated here.
+
         // Channels for communication
-        let (hedge_tx, hedge_rx) = mpsc::channel::<(OrderSide, f64, f64)>(1); // (side, size, avg_price)
+        // (side, size, avg_price, pacifica_epoch, hyperliquid_epoch) - the epochs are
+        // captured at evaluation time so the hedge task can detect a stale view
+        let (hedge_tx, hedge_rx) = mpsc::channel::<(OrderSide, f64, f64, u64, u64)>(1);
         let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>(1);
 
-        // Fill tracking state
-        let processed_fills = Arc::new(tokio::sync::Mutex::new(HashSet::<String>::new()));
-        let last_position_snapshot = Arc::new(tokio::sync::Mutex::new(Option::<PositionSnapshot>::None));
+        // Fill tracking state, rehydrated from durable storage so a restart doesn't
+        // re-hedge a client_order_id it already processed before the crash/restart
+        let rehydrated_fills = store.load_processed_fill_ids().context("Failed to rehydrate processed fills")?;
+        println!(
+            "{} {} Rehydrated {} previously-processed fill(s) from storage",
+            chrono::Utc::now()
+                .format("%Y-%m-%dT%H:%M:%S%.6fZ")
+                .to_string()
+                .bright_black(),
+            "[INIT]".cyan().bold(),
+            rehydrated_fills.len()
+        );
+        let processed_fills = Arc::new(tokio::sync::Mutex::new(rehydrated_fills));
+
+        let rehydrated_snapshot = store
+            .load_last_position_snapshot(&config.symbol)
+            .context("Failed to rehydrate last position snapshot")?
+            .map(|row| PositionSnapshot {
+                amount: row.amount,
+                side: row.side,
+                last_check: Instant::now(),
+            });
+        let last_position_snapshot = Arc::new(tokio::sync::Mutex::new(rehydrated_snapshot));
 
         println!(
             "{} {} {}",
@@ -390,6 +567,9 @@ impl XemmBot {
             pacifica_prices,
             hyperliquid_prices,
             evaluator,
+            risk_budget,
+            metrics,
+            store,
             processed_fills,
             last_position_snapshot,
             hedge_tx,
@@ -397,13 +577,247 @@ impl XemmBot {
             shutdown_tx,
             shutdown_rx: Some(shutdown_rx),
             pacifica_credentials,
+            state_publisher,
+            hedge_feed,
+            serves_metrics: true,
         })
     }
 
-    /// Run the bot - spawn all services and execute main loop
-    pub async fn run(self) -> Result<()> {
-        // TODO: This will be implemented in later phases
-        // For now, just return Ok to allow compilation
+    /// Run the bot - spawn all services and block until shutdown
+    ///
+    /// Composes this symbol's slice of the pipeline: `OrderPlacementService`
+    /// (places orders Pacifica-side), `OrderMonitorService` (the 1kHz
+    /// age/profit-deviation watchdog and REST fill poller), and - via
+    /// `services::order_monitor::spawn_monitor_tasks` - a `HedgeService` that
+    /// drives each detected fill to completion on Hyperliquid. All three are
+    /// wired to the price/state shared between them (`pacifica_prices`,
+    /// `hyperliquid_prices`, `bot_state`, `evaluator`) rather than each
+    /// holding its own copy.
+    pub async fn run(mut self) -> Result<()> {
+        // Metrics endpoint is spawned independently of the trading services below
+        // so /metrics comes up even before the rest of the wiring does.
+        // Under a `Supervisor`, only one symbol's bot actually binds the listener -
+        // see `serves_metrics`.
+        if self.serves_metrics {
+            let metrics_server = crate::metrics::MetricsServer {
+                metrics: self.metrics.clone(),
+                port: self.config.metrics_port,
+            };
+            tokio::spawn(async move {
+                if let Err(e) = metrics_server.run().await {
+                    eprintln!("[METRICS] server exited: {}", e);
+                }
+            });
+
+            // Same one-listener-per-portfolio rule as /metrics: `state_publisher`
+            // and `hedge_feed` are shared across every symbol, so only this
+            // bot binds their HTTP listeners.
+            let state_feed_server = crate::services::state_feed::StateFeedServer {
+                publisher: (*self.state_publisher).clone(),
+                port: self.config.state_feed_port,
+            };
+            tokio::spawn(async move {
+                if let Err(e) = state_feed_server.run().await {
+                    eprintln!("[STATE_FEED] server exited: {}", e);
+                }
+            });
+
+            let hedge_feed_server = crate::services::hedge_feed::HedgeFeedServer {
+                publisher: self.hedge_feed.clone(),
+                port: self.config.hedge_feed_port,
+            };
+            tokio::spawn(async move {
+                if let Err(e) = hedge_feed_server.run().await {
+                    eprintln!("[HEDGE_FEED] server exited: {}", e);
+                }
+            });
+        }
+
+        // Order placement: one request channel per symbol, fed by an
+        // opportunity-evaluation loop that doesn't exist anywhere in this
+        // tree yet. Registering this symbol now so the service is ready to
+        // place orders the moment that loop is wired up; `_order_tx` is held
+        // for the lifetime of this task so the service's receiver doesn't
+        // see a closed channel in the meantime.
+        let (_order_tx, order_rx) = mpsc::channel::<crate::services::order_placement::OrderPlacementRequest>(16);
+        let placement_service = crate::services::order_placement::OrderPlacementService::new(
+            self.pacifica_trading_main.clone(),
+            self.config.clone(),
+            order_rx,
+            self.metrics.clone(),
+            self.risk_budget.clone(),
+            self.state_publisher.clone(),
+        );
+        placement_service.register_symbol(
+            self.config.symbol.clone(),
+            self.bot_state.clone(),
+            self.pacifica_prices.clone(),
+            self.hyperliquid_prices.clone(),
+            self.evaluator.clone(),
+        );
+        tokio::spawn(async move {
+            placement_service.run().await;
+        });
+
+        // Order monitoring + hedging: `atomic_status` is `BotState`'s own
+        // lock-free status handle, shared out here so the 1kHz hot path
+        // never needs to take `bot_state`'s RwLock just to check it.
+        let atomic_status = self.bot_state.read().await.status_atomic.clone();
+        let order_snapshot = Arc::new(SharedOrderSnapshot::new());
+        let (fill_tx, fill_rx) = mpsc::channel::<crate::services::hedge::FillEvent>(16);
+
+        let (monitor_service, cancel_rx) = crate::services::order_monitor::OrderMonitorService::new(
+            self.config.clone(),
+            self.pacifica_trading_monitor.clone(),
+            self.hyperliquid_trading.clone(),
+            self.state_publisher.clone(),
+            self.hedge_feed.clone(),
+            None, // No `ConnectivitySupervisor` wired up for this symbol yet
+        );
+        monitor_service.register_symbol(
+            self.config.symbol.clone(),
+            self.bot_state.clone(),
+            atomic_status,
+            order_snapshot,
+            self.pacifica_prices.clone(),
+            self.hyperliquid_prices.clone(),
+            self.evaluator.clone(),
+            fill_tx,
+        );
+
+        // `spawn_monitor_tasks` also constructs and spawns this symbol's
+        // `HedgeService` (consuming `fill_rx`), so this one call reaches all
+        // three services the review asked for.
+        crate::services::order_monitor::spawn_monitor_tasks(
+            Arc::new(monitor_service),
+            cancel_rx,
+            self.metrics.clone(),
+            self.store.clone(),
+            vec![(self.config.symbol.clone(), fill_rx)],
+        );
+
+        // Block until told to stop - either this process receives ctrl-c, or
+        // (for a bot running standalone outside a `Supervisor`) something
+        // sends on `shutdown_tx` directly.
+        let mut shutdown_rx = self
+            .shutdown_rx
+            .take()
+            .expect("shutdown_rx is always Some immediately after new()/new_with_shared_clients()");
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {
+                println!("[{}] ctrl-c received, shutting down", self.config.symbol);
+            }
+            _ = shutdown_rx.recv() => {
+                println!("[{}] shutdown signal received", self.config.symbol);
+            }
+        }
+
         Ok(())
     }
 }
+
+/// Runs N independent `XemmBot` instances concurrently, one per symbol in
+/// `config.symbols`, sharing exchange connection pools and a portfolio-wide
+/// risk budget, and coordinating a single shutdown across all of them.
+pub struct Supervisor {
+    bots: Vec<XemmBot>,
+    risk_budget: Arc<RiskBudget>,
+}
+
+impl Supervisor {
+    /// Load config, connect one shared set of exchange clients, and build one
+    /// `XemmBot` per symbol in `config.symbols`
+    pub async fn new() -> Result<Self> {
+        let config = Config::load_default().context("Failed to load config.json")?;
+        config.validate().context("Invalid configuration")?;
+
+        if config.symbols.is_empty() {
+            anyhow::bail!("config.symbols must list at least one symbol for the Supervisor to run");
+        }
+
+        let shared = SharedExchangeClients::connect(&config.state_db_path).await?;
+        let risk_budget = Arc::new(RiskBudget::new(config.max_gross_notional_usd, config.max_net_delta_usd));
+
+        println!(
+            "[SUPERVISOR] Starting {} symbol(s): {}",
+            config.symbols.len(),
+            config.symbols.join(", ")
+        );
+
+        let mut bots = Vec::with_capacity(config.symbols.len());
+        for (i, symbol) in config.symbols.iter().enumerate() {
+            let mut bot = XemmBot::new_with_shared_clients(
+                config.clone(),
+                symbol.clone(),
+                shared.clone(),
+                risk_budget.clone(),
+            )
+            .await
+            .with_context(|| format!("Failed to initialize XemmBot for symbol {}", symbol))?;
+            // Only the first symbol's bot binds the shared /metrics listener
+            bot.serves_metrics = i == 0;
+            bots.push(bot);
+        }
+
+        Ok(Self { bots, risk_budget })
+    }
+
+    /// Run every symbol's bot concurrently, then cancel every symbol's open
+    /// Pacifica orders once they've all returned - the one shutdown path for
+    /// the whole portfolio, rather than one per symbol.
+    pub async fn run(self) -> Result<()> {
+        let cancel_targets: Vec<(String, Arc<PacificaTrading>)> = self
+            .bots
+            .iter()
+            .map(|bot| (bot.config.symbol.clone(), bot.pacifica_trading_main.clone()))
+            .collect();
+        let shutdown_txs: Vec<_> = self.bots.iter().map(|bot| bot.shutdown_tx.clone()).collect();
+
+        let handles: Vec<_> = self
+            .bots
+            .into_iter()
+            .map(|bot| {
+                let symbol = bot.config.symbol.clone();
+                tokio::spawn(async move { (symbol, bot.run().await) })
+            })
+            .collect();
+
+        let mut first_err = None;
+        for handle in handles {
+            match handle.await {
+                Ok((_, Ok(()))) => {}
+                Ok((symbol, Err(e))) => {
+                    eprintln!("[SUPERVISOR] {} exited with error: {}", symbol, e);
+                    first_err.get_or_insert(e);
+                }
+                Err(join_err) => {
+                    first_err.get_or_insert(anyhow::anyhow!("bot task panicked: {}", join_err));
+                }
+            }
+        }
+
+        Self::cancel_all(&cancel_targets).await;
+        for tx in shutdown_txs {
+            let _ = tx.send(()).await;
+        }
+
+        match first_err {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    async fn cancel_all(targets: &[(String, Arc<PacificaTrading>)]) {
+        for (symbol, client) in targets {
+            match client.cancel_all_orders(false, Some(symbol), false).await {
+                Ok(count) => println!("[SUPERVISOR] Cancelled {} order(s) for {}", count, symbol),
+                Err(e) => eprintln!("[SUPERVISOR] Failed to cancel orders for {}: {}", symbol, e),
+            }
+        }
+    }
+
+    /// The portfolio-wide risk budget shared by every symbol this supervisor runs
+    pub fn risk_budget(&self) -> Arc<RiskBudget> {
+        self.risk_budget.clone()
+    }
+}
@@ -0,0 +1,218 @@
+use std::time::Instant;
+
+use crate::strategy::OrderSide;
+
+/// Hedge execution state for a single maker fill being driven through the
+/// optimistic hedge path: assume the Hyperliquid taker leg fills cleanly, but
+/// keep enough state to retry with widened slippage or roll back if it
+/// doesn't. Every fill moves through `Pending -> Hedging -> Hedged` on the
+/// happy path; once slippage retries are exhausted it moves into
+/// `RollingBack` instead of jumping straight to a terminal state, so the
+/// in-flight rollback attempt itself (a widened marketable cross, or an
+/// immediate flatten - see `RollbackMode`) is visible for the duration it
+/// takes to resolve, not just its outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HedgeState {
+    /// Fill detected, hedge not yet submitted
+    Pending,
+    /// Hedge order submitted; `attempt` counts retries with widened slippage
+    Hedging { attempt: u32 },
+    /// Residual size reached zero - the fill is fully hedged (settled)
+    Hedged { hedge_avg_price: f64 },
+    /// Slippage retries exhausted; rolling back per `RollbackMode` - either a
+    /// final widened-cross hedge attempt or a direct flatten, not yet resolved
+    RollingBack { reason: String },
+    /// Rollback resolved to an unwound (flattened) naked leg
+    Failed { reason: String },
+}
+
+/// Action the caller should take after a failed or short hedge attempt
+#[derive(Debug, Clone, PartialEq)]
+pub enum HedgeAction {
+    /// Resubmit the hedge for `residual_size` with wider allowed slippage
+    RetryWithWidenedSlippage { residual_size: f64 },
+    /// Slippage retries are exhausted; make one final attempt at a
+    /// marketable cross (a much wider, near-guaranteed-to-fill price) before
+    /// falling back to `UnwindOnPacifica` if even that comes up short
+    RollbackWidenedCross { residual_size: f64 },
+    /// Give up on hedging; flatten `residual_size` with a Pacifica market order instead
+    UnwindOnPacifica { residual_size: f64 },
+}
+
+/// Which rollback action `ExecutableMatch::next_action` takes once slippage
+/// retries are exhausted - configurable per-deployment since whether it's
+/// better to keep chasing a fill on Hyperliquid or to cut the naked Pacifica
+/// leg loose immediately depends on venue liquidity the bot doesn't model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollbackMode {
+    /// Try one more hedge at a widened marketable-cross price before unwinding
+    WidenedCross,
+    /// Skip the extra hedge attempt and flatten the maker leg on Pacifica directly
+    FlattenOnPacifica,
+}
+
+/// A single maker fill tracked end-to-end through the optimistic hedge path:
+/// `Pending -> Hedging -> Hedged` on the happy path, or `Hedging -> RollingBack`
+/// once retries are exhausted. Under `RollbackMode::WidenedCross` a rolled-back
+/// match can still end `Hedged`; otherwise it ends `Failed`, at which point the
+/// residual size is flattened on Pacifica instead of being left as an
+/// unmanaged directional position.
+#[derive(Debug, Clone)]
+pub struct ExecutableMatch {
+    pub fill_id: String,
+    pub side: OrderSide,
+    pub size: f64,
+    pub maker_avg_price: f64,
+    pub hedge_state: HedgeState,
+    /// Quantity still unhedged; starts at `size` and is decremented as hedge fills land
+    pub residual_size: f64,
+    pub created_at: Instant,
+}
+
+impl ExecutableMatch {
+    pub fn new(fill_id: String, side: OrderSide, size: f64, maker_avg_price: f64) -> Self {
+        Self {
+            fill_id,
+            side,
+            size,
+            maker_avg_price,
+            hedge_state: HedgeState::Pending,
+            residual_size: size,
+            created_at: Instant::now(),
+        }
+    }
+
+    /// Mark a hedge attempt as in flight, bumping the retry counter if one was already underway
+    pub fn begin_hedging(&mut self) {
+        let attempt = match self.hedge_state {
+            HedgeState::Hedging { attempt } => attempt + 1,
+            _ => 1,
+        };
+        self.hedge_state = HedgeState::Hedging { attempt };
+    }
+
+    /// Record a (possibly partial) hedge fill at `hedge_avg_price`, returning the remaining residual size
+    pub fn record_hedge_fill(&mut self, filled_size: f64, hedge_avg_price: f64) -> f64 {
+        self.residual_size = (self.residual_size - filled_size).max(0.0);
+        if self.residual_size <= 0.0 {
+            self.hedge_state = HedgeState::Hedged { hedge_avg_price };
+        }
+        self.residual_size
+    }
+
+    /// Whether the residual size has been fully hedged
+    pub fn is_fully_hedged(&self) -> bool {
+        matches!(self.hedge_state, HedgeState::Hedged { .. })
+    }
+
+    /// Decide what to do after a failed or short hedge attempt: retry with
+    /// widened slippage while under `max_slippage_retries`, otherwise enter
+    /// `RollingBack` and resolve it per `rollback_mode` - either one last
+    /// widened-cross attempt or an immediate flatten
+    pub fn next_action(&mut self, max_slippage_retries: u32, rollback_mode: RollbackMode, reason: impl Into<String>) -> HedgeAction {
+        if let HedgeState::Hedging { attempt } = self.hedge_state {
+            if attempt < max_slippage_retries {
+                return HedgeAction::RetryWithWidenedSlippage { residual_size: self.residual_size };
+            }
+        }
+        let reason = reason.into();
+        self.hedge_state = HedgeState::RollingBack { reason: reason.clone() };
+        match rollback_mode {
+            RollbackMode::WidenedCross => HedgeAction::RollbackWidenedCross { residual_size: self.residual_size },
+            RollbackMode::FlattenOnPacifica => {
+                self.hedge_state = HedgeState::Failed { reason };
+                HedgeAction::UnwindOnPacifica { residual_size: self.residual_size }
+            }
+        }
+    }
+
+    /// The widened-cross rollback attempt itself came up short (or errored);
+    /// there's nothing left to try but flatten the naked leg
+    pub fn fail_rollback(&mut self, reason: impl Into<String>) {
+        self.hedge_state = HedgeState::Failed { reason: reason.into() };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_match(size: f64) -> ExecutableMatch {
+        ExecutableMatch::new("fill-1".to_string(), OrderSide::Buy, size, 100.0)
+    }
+
+    #[test]
+    fn test_begin_hedging_increments_attempt_across_calls() {
+        let mut m = new_match(10.0);
+        m.begin_hedging();
+        assert_eq!(m.hedge_state, HedgeState::Hedging { attempt: 1 });
+        m.begin_hedging();
+        assert_eq!(m.hedge_state, HedgeState::Hedging { attempt: 2 });
+    }
+
+    #[test]
+    fn test_record_hedge_fill_fully_clears_residual_transitions_to_hedged() {
+        let mut m = new_match(10.0);
+        m.begin_hedging();
+        let remaining = m.record_hedge_fill(10.0, 99.5);
+        assert_eq!(remaining, 0.0);
+        assert!(m.is_fully_hedged());
+        assert_eq!(m.hedge_state, HedgeState::Hedged { hedge_avg_price: 99.5 });
+    }
+
+    #[test]
+    fn test_record_hedge_fill_partial_stays_not_hedged() {
+        let mut m = new_match(10.0);
+        m.begin_hedging();
+        let remaining = m.record_hedge_fill(4.0, 99.5);
+        assert_eq!(remaining, 6.0);
+        assert!(!m.is_fully_hedged());
+    }
+
+    #[test]
+    fn test_next_action_retries_with_widened_slippage_under_max() {
+        let mut m = new_match(10.0);
+        m.begin_hedging(); // attempt 1
+        let action = m.next_action(3, RollbackMode::WidenedCross, "short fill");
+        assert_eq!(action, HedgeAction::RetryWithWidenedSlippage { residual_size: m.residual_size });
+        // Still mid-retry, not rolled back
+        assert!(matches!(m.hedge_state, HedgeState::Hedging { .. }));
+    }
+
+    #[test]
+    fn test_next_action_exhausted_retries_rolls_back_to_widened_cross() {
+        let mut m = new_match(10.0);
+        // Drive to the retry ceiling: begin_hedging bumps attempt each call
+        for _ in 0..3 {
+            m.begin_hedging();
+        }
+        let action = m.next_action(3, RollbackMode::WidenedCross, "hedge order filled short");
+        assert_eq!(action, HedgeAction::RollbackWidenedCross { residual_size: m.residual_size });
+        assert!(matches!(m.hedge_state, HedgeState::RollingBack { .. }));
+        // Not yet Failed - the widened-cross attempt itself hasn't resolved
+        assert!(!matches!(m.hedge_state, HedgeState::Failed { .. }));
+    }
+
+    #[test]
+    fn test_next_action_exhausted_retries_flattens_directly_in_flatten_mode() {
+        let mut m = new_match(10.0);
+        for _ in 0..3 {
+            m.begin_hedging();
+        }
+        let action = m.next_action(3, RollbackMode::FlattenOnPacifica, "hedge order filled short");
+        assert_eq!(action, HedgeAction::UnwindOnPacifica { residual_size: m.residual_size });
+        assert_eq!(m.hedge_state, HedgeState::Failed { reason: "hedge order filled short".to_string() });
+    }
+
+    #[test]
+    fn test_fail_rollback_after_widened_cross_falls_short() {
+        let mut m = new_match(10.0);
+        m.begin_hedging();
+        m.hedge_state = HedgeState::RollingBack { reason: "hedge order filled short".to_string() };
+        m.record_hedge_fill(4.0, 101.0); // widened cross itself only partially fills
+        m.fail_rollback("widened cross filled short");
+        assert_eq!(m.hedge_state, HedgeState::Failed { reason: "widened cross filled short".to_string() });
+        // residual_size (what unwind() should flatten) reflects the partial fill
+        assert_eq!(m.residual_size, 6.0);
+    }
+}
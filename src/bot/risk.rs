@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Per-symbol exposure last reported into a `RiskBudget`
+#[derive(Debug, Clone, Copy, Default)]
+struct SymbolExposure {
+    /// Absolute notional currently resting/held for this symbol, in USD
+    gross_notional_usd: f64,
+    /// Signed notional (+ long, - short) currently held for this symbol, in USD
+    net_delta_usd: f64,
+}
+
+/// Aggregate risk budget shared across every symbol a `Supervisor` runs, so a
+/// portfolio of XEMM instances doesn't collectively take on more gross notional
+/// or net directional exposure than the operator configured, even though each
+/// symbol only sees its own opportunities.
+///
+/// Cheap to clone (wrap in `Arc`) and safe to call from every symbol's order
+/// placement task concurrently.
+#[derive(Debug)]
+pub struct RiskBudget {
+    max_gross_notional_usd: f64,
+    max_net_delta_usd: f64,
+    exposures: Mutex<HashMap<String, SymbolExposure>>,
+}
+
+impl RiskBudget {
+    /// Create a risk budget with the given aggregate limits
+    ///
+    /// # Arguments
+    /// * `max_gross_notional_usd` - Ceiling on summed absolute notional across all symbols
+    /// * `max_net_delta_usd` - Ceiling on the absolute value of summed signed notional across all symbols
+    pub fn new(max_gross_notional_usd: f64, max_net_delta_usd: f64) -> Self {
+        Self {
+            max_gross_notional_usd,
+            max_net_delta_usd,
+            exposures: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// An unconstrained budget, for a single-symbol `XemmBot` run standalone
+    /// outside of a `Supervisor`
+    pub fn unconstrained() -> Self {
+        Self::new(f64::INFINITY, f64::INFINITY)
+    }
+
+    /// Whether placing an order for `symbol` with the given additional notional
+    /// and signed delta would keep the portfolio within budget, given every
+    /// other symbol's last-reported exposure
+    ///
+    /// # Arguments
+    /// * `symbol` - The symbol about to place an order (its own stale exposure is excluded)
+    /// * `additional_notional_usd` - Absolute notional of the candidate order, in USD
+    /// * `additional_delta_usd` - Signed notional of the candidate order, in USD
+    pub fn allows(&self, symbol: &str, additional_notional_usd: f64, additional_delta_usd: f64) -> bool {
+        let exposures = self.exposures.lock().unwrap();
+        let mut gross = additional_notional_usd;
+        let mut net = additional_delta_usd;
+        for (other_symbol, exposure) in exposures.iter() {
+            if other_symbol == symbol {
+                continue;
+            }
+            gross += exposure.gross_notional_usd;
+            net += exposure.net_delta_usd;
+        }
+        gross <= self.max_gross_notional_usd && net.abs() <= self.max_net_delta_usd
+    }
+
+    /// Record `symbol`'s latest gross notional and signed net delta, in USD,
+    /// superseding whatever was previously reported for it
+    pub fn update_exposure(&self, symbol: &str, gross_notional_usd: f64, net_delta_usd: f64) {
+        let mut exposures = self.exposures.lock().unwrap();
+        exposures.insert(
+            symbol.to_string(),
+            SymbolExposure {
+                gross_notional_usd,
+                net_delta_usd,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows_within_budget() {
+        let budget = RiskBudget::new(1000.0, 500.0);
+        assert!(budget.allows("BTC", 400.0, 400.0));
+    }
+
+    #[test]
+    fn test_rejects_when_gross_notional_exceeded() {
+        let budget = RiskBudget::new(1000.0, 1000.0);
+        budget.update_exposure("BTC", 700.0, 700.0);
+
+        assert!(!budget.allows("ETH", 400.0, 0.0));
+        assert!(budget.allows("ETH", 200.0, 0.0));
+    }
+
+    #[test]
+    fn test_rejects_when_net_delta_exceeded() {
+        let budget = RiskBudget::new(10_000.0, 500.0);
+        budget.update_exposure("BTC", 600.0, 600.0);
+
+        // Adding more long delta on top of BTC's existing long delta breaches the cap
+        assert!(!budget.allows("ETH", 100.0, 100.0));
+        // But an offsetting short delta nets back under the cap
+        assert!(budget.allows("ETH", 700.0, -700.0));
+    }
+
+    #[test]
+    fn test_symbol_own_stale_exposure_is_excluded_from_its_own_check() {
+        let budget = RiskBudget::new(500.0, 500.0);
+        budget.update_exposure("BTC", 400.0, 400.0);
+
+        // BTC replacing its own 400 with 450 shouldn't double-count the old 400
+        assert!(budget.allows("BTC", 450.0, 450.0));
+    }
+
+    #[test]
+    fn test_unconstrained_always_allows() {
+        let budget = RiskBudget::unconstrained();
+        assert!(budget.allows("BTC", 1_000_000.0, 1_000_000.0));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::analytics::OpportunityRecord;
 use crate::strategy::OrderSide;
 use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
@@ -10,12 +11,17 @@ pub enum BotStatus {
     Idle,
     /// Order has been placed on Pacifica
     OrderPlaced,
+    /// Order has received one or more fills but is not yet fully filled
+    PartiallyFilled,
     /// Order has been filled on Pacifica
     Filled,
     /// Hedge is being executed on Hyperliquid
     Hedging,
     /// Full cycle complete (order filled + hedged)
     Complete,
+    /// Hedge kept failing; flattening the filled Pacifica position with a
+    /// market order to return to flat rather than carrying naked exposure
+    Unwinding,
     /// Error occurred
     Error(String),
 }
@@ -37,6 +43,10 @@ pub struct ActiveOrder {
     pub initial_profit_bps: f64,
     /// When the order was placed
     pub placed_at: Instant,
+    /// Cumulative filled size reported so far, keyed implicitly by `client_order_id`
+    pub filled_size: f64,
+    /// Filled size that has not yet been hedged on Hyperliquid
+    pub unhedged_size: f64,
 }
 
 /// Bot state (thread-safe via Arc<RwLock<BotState>>)
@@ -48,10 +58,16 @@ pub struct BotState {
     pub position: f64,
     /// Current bot status
     pub status: BotStatus,
-    /// Atomic status for fast lock-free checks (0=Idle, 1=OrderPlaced, 2=Filled, 3=Hedging, 4=Complete, 5=Error)
+    /// Atomic status for fast lock-free checks (0=Idle, 1=OrderPlaced, 2=Filled, 3=Hedging, 4=Complete, 5=Error, 6=PartiallyFilled, 7=Unwinding)
     pub status_atomic: Arc<AtomicU8>,
     /// Last time an order was cancelled (for grace period enforcement)
     pub last_cancellation_time: Option<Instant>,
+    /// Number of consecutive hedge attempts that have failed for the current fill
+    pub hedge_retry_count: u32,
+    /// When the first hedge failure in the current retry streak was recorded
+    pub first_hedge_failure_at: Option<Instant>,
+    /// Realized loss from the most recent unwind, if any (positive = loss)
+    pub last_unwind_loss: Option<f64>,
 }
 
 impl BotState {
@@ -63,6 +79,9 @@ impl BotState {
             status: BotStatus::Idle,
             status_atomic: Arc::new(AtomicU8::new(0)), // 0 = Idle
             last_cancellation_time: None,
+            hedge_retry_count: 0,
+            first_hedge_failure_at: None,
+            last_unwind_loss: None,
         }
     }
 
@@ -81,16 +100,108 @@ impl BotState {
         self.last_cancellation_time = Some(Instant::now());
     }
 
-    /// Mark order as filled
-    pub fn mark_filled(&mut self, filled_size: f64, side: OrderSide) {
-        self.status = BotStatus::Filled;
-        self.status_atomic.store(2, Ordering::Release); // 2 = Filled
+    /// Record a fill against the active order, keyed by `client_order_id`
+    ///
+    /// `cumulative_filled` is the total filled size reported so far for this
+    /// order (not a per-fill delta), matching how exchanges report fills on
+    /// `get_open_orders`. Only the newly-observed delta is applied, so
+    /// repeated or out-of-order reports of the same cumulative amount are a
+    /// no-op. Transitions to `PartiallyFilled` until the order is fully
+    /// filled, at which point it transitions to `Filled`.
+    ///
+    /// Returns the newly-filled delta (0.0 if the order doesn't match or the
+    /// report is stale/duplicate), which is exactly the quantity that still
+    /// needs to be hedged on Hyperliquid for this fill.
+    pub fn mark_filled(&mut self, client_order_id: &str, cumulative_filled: f64, side: OrderSide) -> f64 {
+        let Some(order) = self.active_order.as_mut() else {
+            return 0.0;
+        };
+        if order.client_order_id != client_order_id {
+            return 0.0;
+        }
+
+        let delta = cumulative_filled - order.filled_size;
+        if delta <= 0.0 {
+            return 0.0;
+        }
+
+        order.filled_size = cumulative_filled;
+        order.unhedged_size += delta;
 
-        // Update position
         match side {
-            OrderSide::Buy => self.position += filled_size,
-            OrderSide::Sell => self.position -= filled_size,
+            OrderSide::Buy => self.position += delta,
+            OrderSide::Sell => self.position -= delta,
+        }
+
+        if order.filled_size >= order.size {
+            self.status = BotStatus::Filled;
+            self.status_atomic.store(2, Ordering::Release); // 2 = Filled
+        } else {
+            self.status = BotStatus::PartiallyFilled;
+            self.status_atomic.store(6, Ordering::Release); // 6 = PartiallyFilled
+        }
+
+        delta
+    }
+
+    /// Currently-filled quantity that has not yet been hedged on Hyperliquid
+    pub fn unhedged_filled_size(&self) -> f64 {
+        self.active_order.as_ref().map(|o| o.unhedged_size).unwrap_or(0.0)
+    }
+
+    /// Record that `amount` of the unhedged filled quantity has been hedged
+    pub fn consume_hedged_size(&mut self, amount: f64) {
+        if let Some(order) = self.active_order.as_mut() {
+            order.unhedged_size = (order.unhedged_size - amount).max(0.0);
+        }
+    }
+
+    /// Fold a successfully-hedged chunk into the active order and, if that
+    /// was the last unhedged piece of an order that's also fully filled,
+    /// close out the cycle via `mark_complete`.
+    ///
+    /// A single order can be hedged across several of these calls (fills
+    /// accumulate into chunks via `FillAccumulator`, each dispatched as its
+    /// own hedge), so this only finishes the cycle once there's nothing left
+    /// to fill *and* nothing left to hedge - otherwise it reports the order's
+    /// current fill state so the bot doesn't stay parked in `Hedging` while
+    /// waiting on the rest of the order.
+    ///
+    /// # Arguments
+    /// * `amount_hedged` - Size just hedged, folded into `consume_hedged_size`
+    /// * `hyperliquid_price` - This chunk's hedge leg average fill price, passed straight through to `mark_complete`
+    /// * `realized_profit_bps` - This chunk's realized profit, passed straight through to `mark_complete`
+    /// * `opened_at_ms` / `closed_at_ms` - Passed straight through to `mark_complete`
+    pub fn finish_hedge(
+        &mut self,
+        amount_hedged: f64,
+        hyperliquid_price: f64,
+        realized_profit_bps: f64,
+        opened_at_ms: u64,
+        closed_at_ms: u64,
+    ) -> Option<OpportunityRecord> {
+        self.consume_hedged_size(amount_hedged);
+
+        let Some(order) = self.active_order.as_ref() else {
+            return None;
+        };
+
+        if order.filled_size >= order.size && order.unhedged_size <= 0.0 {
+            return self.mark_complete(hyperliquid_price, realized_profit_bps, opened_at_ms, closed_at_ms);
+        }
+
+        // More of this order is still resting on Pacifica or still unhedged -
+        // fall back to the same status `mark_filled` would report for it,
+        // rather than leaving `status` parked at `Hedging` until the next
+        // fill arrives
+        if order.filled_size >= order.size {
+            self.status = BotStatus::Filled;
+            self.status_atomic.store(2, Ordering::Release); // 2 = Filled
+        } else {
+            self.status = BotStatus::PartiallyFilled;
+            self.status_atomic.store(6, Ordering::Release); // 6 = PartiallyFilled
         }
+        None
     }
 
     /// Mark as hedging
@@ -99,11 +210,98 @@ impl BotState {
         self.status_atomic.store(3, Ordering::Release); // 3 = Hedging
     }
 
+    /// Record a failed hedge attempt and decide whether to unwind
+    ///
+    /// Returns `true` once `max_retries` consecutive failures have occurred
+    /// within `deadline_secs` of the first failure, signalling the caller
+    /// should flatten the naked position via `begin_unwind` instead of
+    /// retrying the hedge again. The retry streak resets once the deadline
+    /// elapses, so a failure far in the future starts a fresh streak.
+    pub fn record_hedge_failure(&mut self, max_retries: u32, deadline_secs: u64) -> bool {
+        if let Some(first_failure) = self.first_hedge_failure_at {
+            if first_failure.elapsed().as_secs() > deadline_secs {
+                // Previous streak expired; start a new one
+                self.hedge_retry_count = 0;
+                self.first_hedge_failure_at = None;
+            }
+        }
+
+        self.hedge_retry_count += 1;
+        if self.first_hedge_failure_at.is_none() {
+            self.first_hedge_failure_at = Some(Instant::now());
+        }
+
+        self.hedge_retry_count >= max_retries
+    }
+
+    /// Clear the hedge retry streak after a successful hedge
+    pub fn reset_hedge_retries(&mut self) {
+        self.hedge_retry_count = 0;
+        self.first_hedge_failure_at = None;
+    }
+
+    /// Begin unwinding a naked position after repeated hedge failures
+    pub fn begin_unwind(&mut self) {
+        self.status = BotStatus::Unwinding;
+        self.status_atomic.store(7, Ordering::Release); // 7 = Unwinding
+    }
+
+    /// Finish an unwind: flatten is complete, record the realized loss, and
+    /// return to `Idle` rather than parking in `Error` so the bot keeps trading
+    pub fn finish_unwind(&mut self, realized_loss: f64) {
+        self.last_unwind_loss = Some(realized_loss);
+        self.active_order = None;
+        self.position = 0.0;
+        self.reset_hedge_retries();
+        self.status = BotStatus::Idle;
+        self.status_atomic.store(0, Ordering::Release); // 0 = Idle
+        self.last_cancellation_time = Some(Instant::now());
+    }
+
     /// Mark as complete
-    pub fn mark_complete(&mut self) {
+    ///
+    /// Builds a closed-cycle `OpportunityRecord` from the active order before
+    /// clearing it, so the caller can hand it off to an `OpportunityRecorder`.
+    /// Returns `None` if there was no active order (nothing to record).
+    ///
+    /// `Complete` is momentary, the same way `Unwinding` is on the rollback
+    /// path: the cycle is done, not a reason to stay parked, so this returns
+    /// `status` to `Idle` before handing the record back - otherwise nothing
+    /// would ever flip it back and the symbol could never trade again.
+    ///
+    /// # Arguments
+    /// * `hyperliquid_price` - The hedge leg's actual average fill price (`ExecutableMatch::hedge_state`'s
+    ///   `HedgeState::Hedged::hedge_avg_price`), not the Pacifica maker price
+    /// * `realized_profit_bps` - Actual profit captured after the hedge, vs. `initial_profit_bps`
+    /// * `opened_at_ms` - When the opportunity was originally evaluated, in epoch milliseconds
+    /// * `closed_at_ms` - When the hedge completed, in epoch milliseconds
+    pub fn mark_complete(
+        &mut self,
+        hyperliquid_price: f64,
+        realized_profit_bps: f64,
+        opened_at_ms: u64,
+        closed_at_ms: u64,
+    ) -> Option<OpportunityRecord> {
         self.status = BotStatus::Complete;
         self.status_atomic.store(4, Ordering::Release); // 4 = Complete
-        self.active_order = None;
+        self.reset_hedge_retries();
+
+        let record = self.active_order.take().map(|order| OpportunityRecord {
+            direction: order.side,
+            pacifica_price: order.price,
+            hyperliquid_price,
+            size: order.size,
+            initial_profit_bps: order.initial_profit_bps,
+            realized_profit_bps,
+            opened_at_ms,
+            closed_at_ms,
+        });
+
+        self.status = BotStatus::Idle;
+        self.status_atomic.store(0, Ordering::Release); // 0 = Idle
+        self.last_cancellation_time = Some(Instant::now());
+
+        record
     }
 
     /// Set error status
@@ -113,6 +311,9 @@ impl BotState {
     }
 
     /// Check if bot is in a terminal state
+    ///
+    /// `Unwinding` is deliberately excluded: it is a recovery flow that ends
+    /// by returning to `Idle` via `finish_unwind`, not a dead end like `Error`.
     pub fn is_terminal(&self) -> bool {
         matches!(self.status, BotStatus::Complete | BotStatus::Error(_))
     }
@@ -152,3 +353,146 @@ impl Default for BotState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(size: f64) -> ActiveOrder {
+        ActiveOrder {
+            client_order_id: "cloid-1".to_string(),
+            symbol: "SOL".to_string(),
+            side: OrderSide::Buy,
+            price: 100.0,
+            size,
+            initial_profit_bps: 5.0,
+            placed_at: Instant::now(),
+            filled_size: 0.0,
+            unhedged_size: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_mark_filled_accumulates_delta_and_transitions_partially_filled() {
+        let mut state = BotState::new();
+        state.set_active_order(order(10.0));
+
+        let delta = state.mark_filled("cloid-1", 4.0, OrderSide::Buy);
+        assert_eq!(delta, 4.0);
+        assert_eq!(state.status, BotStatus::PartiallyFilled);
+        assert_eq!(state.position, 4.0);
+        assert_eq!(state.unhedged_filled_size(), 4.0);
+    }
+
+    #[test]
+    fn test_mark_filled_reaches_filled_once_cumulative_matches_size() {
+        let mut state = BotState::new();
+        state.set_active_order(order(10.0));
+
+        state.mark_filled("cloid-1", 4.0, OrderSide::Buy);
+        let delta = state.mark_filled("cloid-1", 10.0, OrderSide::Buy);
+        assert_eq!(delta, 6.0);
+        assert_eq!(state.status, BotStatus::Filled);
+        assert_eq!(state.position, 10.0);
+    }
+
+    #[test]
+    fn test_mark_filled_stale_or_duplicate_report_is_a_no_op() {
+        let mut state = BotState::new();
+        state.set_active_order(order(10.0));
+
+        state.mark_filled("cloid-1", 5.0, OrderSide::Buy);
+        // Same cumulative amount reported again (e.g. by the other detection layer)
+        let delta = state.mark_filled("cloid-1", 5.0, OrderSide::Buy);
+        assert_eq!(delta, 0.0);
+        assert_eq!(state.position, 5.0);
+    }
+
+    #[test]
+    fn test_mark_filled_wrong_client_order_id_is_ignored() {
+        let mut state = BotState::new();
+        state.set_active_order(order(10.0));
+
+        let delta = state.mark_filled("some-other-cloid", 5.0, OrderSide::Buy);
+        assert_eq!(delta, 0.0);
+        assert_eq!(state.position, 0.0);
+    }
+
+    #[test]
+    fn test_record_hedge_failure_signals_unwind_once_max_retries_hit_within_deadline() {
+        let mut state = BotState::new();
+        assert!(!state.record_hedge_failure(3, 60));
+        assert!(!state.record_hedge_failure(3, 60));
+        assert!(state.record_hedge_failure(3, 60));
+    }
+
+    #[test]
+    fn test_reset_hedge_retries_clears_the_streak() {
+        let mut state = BotState::new();
+        state.record_hedge_failure(3, 60);
+        state.record_hedge_failure(3, 60);
+        state.reset_hedge_retries();
+        // Streak restarted - two more failures shouldn't hit a 3-retry ceiling yet
+        assert!(!state.record_hedge_failure(3, 60));
+        assert!(!state.record_hedge_failure(3, 60));
+    }
+
+    #[test]
+    fn test_begin_unwind_then_finish_unwind_returns_to_idle_with_realized_loss() {
+        let mut state = BotState::new();
+        state.set_active_order(order(10.0));
+        state.mark_filled("cloid-1", 10.0, OrderSide::Buy);
+        state.record_hedge_failure(3, 60);
+
+        state.begin_unwind();
+        assert_eq!(state.status, BotStatus::Unwinding);
+        assert!(!state.is_idle());
+
+        state.finish_unwind(12.5);
+        assert!(state.is_idle());
+        assert!(state.is_idle_fast());
+        assert_eq!(state.last_unwind_loss, Some(12.5));
+        assert_eq!(state.position, 0.0);
+        assert!(state.active_order.is_none());
+        // A clean unwind isn't a hard error
+        assert!(!state.is_terminal());
+    }
+
+    #[test]
+    fn test_finish_hedge_after_simulated_fill_and_hedge_returns_to_idle() {
+        // Regression test for the bug where a successful hedge left BotState
+        // stuck in Hedging forever: nothing ever called mark_complete.
+        let mut state = BotState::new();
+        state.set_active_order(order(10.0));
+        state.mark_filled("cloid-1", 10.0, OrderSide::Buy);
+        state.mark_hedging();
+        assert!(!state.is_idle());
+
+        let record = state.finish_hedge(10.0, 150.0, 8.0, 1_000, 2_000);
+        let record = record.expect("fully filled and fully hedged order should produce a closed-cycle record");
+        assert_eq!(record.direction, OrderSide::Buy);
+        assert_eq!(record.size, 10.0);
+        assert_eq!(record.hyperliquid_price, 150.0);
+        assert_eq!(record.realized_profit_bps, 8.0);
+
+        assert!(state.is_idle());
+        assert!(state.is_idle_fast());
+        assert!(state.active_order.is_none());
+    }
+
+    #[test]
+    fn test_finish_hedge_partial_chunk_does_not_clear_active_order() {
+        // An order still being incrementally filled/hedged shouldn't be torn
+        // down by the first chunk's hedge completing.
+        let mut state = BotState::new();
+        state.set_active_order(order(10.0));
+        state.mark_filled("cloid-1", 4.0, OrderSide::Buy);
+        state.mark_hedging();
+
+        let record = state.finish_hedge(4.0, 150.0, 8.0, 1_000, 2_000);
+        assert!(record.is_none());
+        assert!(state.active_order.is_some());
+        assert_eq!(state.status, BotStatus::PartiallyFilled);
+        assert!(!state.is_idle());
+    }
+}
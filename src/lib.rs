@@ -1,7 +1,10 @@
 // Library exports for xemm_rust
 
+pub mod analytics;
 pub mod connector;
 pub mod config;
+pub mod metrics;
+pub mod storage;
 pub mod strategy;
 pub mod bot;
 pub mod trade_fetcher;
@@ -0,0 +1,171 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use hdrhistogram::Histogram;
+use parking_lot::Mutex;
+use tracing::info;
+
+use crate::services::fill_ledger::FillSource;
+
+/// p50/p90/p99, in microseconds, read off a `hdrhistogram::Histogram`
+#[derive(Debug, Clone, Copy)]
+pub struct Percentiles {
+    pub p50_us: u64,
+    pub p90_us: u64,
+    pub p99_us: u64,
+}
+
+impl Percentiles {
+    fn from_histogram(histogram: &Histogram<u64>) -> Self {
+        Self {
+            p50_us: histogram.value_at_quantile(0.50),
+            p90_us: histogram.value_at_quantile(0.90),
+            p99_us: histogram.value_at_quantile(0.99),
+        }
+    }
+}
+
+/// A point-in-time read of one `FillSource`'s latency breakdown, for a
+/// caller that wants the numbers directly rather than the periodic log line
+pub struct FillLatencySnapshot {
+    pub source: FillSource,
+    pub samples: u64,
+    pub detect_to_dispatch: Percentiles,
+    pub dispatch_to_ack: Percentiles,
+    pub end_to_end: Percentiles,
+}
+
+/// Three `hdrhistogram`s tracking one `FillSource`'s journey through the
+/// fill-detection -> hedge pipeline, each bounded to the same precision as
+/// `OrderMonitorService::HotPathProfiler` uses for its own latency tracking
+struct SourceLatency {
+    detect_to_dispatch_us: Mutex<Histogram<u64>>,
+    dispatch_to_ack_us: Mutex<Histogram<u64>>,
+    end_to_end_us: Mutex<Histogram<u64>>,
+    samples: AtomicU64,
+}
+
+impl SourceLatency {
+    fn new() -> Self {
+        Self {
+            detect_to_dispatch_us: Mutex::new(Histogram::new(3).expect("valid histogram precision")),
+            dispatch_to_ack_us: Mutex::new(Histogram::new(3).expect("valid histogram precision")),
+            end_to_end_us: Mutex::new(Histogram::new(3).expect("valid histogram precision")),
+            samples: AtomicU64::new(0),
+        }
+    }
+
+    fn record(histogram: &Mutex<Histogram<u64>>, elapsed: Duration) {
+        let micros = elapsed.as_micros().min(u64::MAX as u128) as u64;
+        let _ = histogram.lock().record(micros);
+    }
+
+    fn snapshot(&self, source: FillSource) -> FillLatencySnapshot {
+        FillLatencySnapshot {
+            source,
+            samples: self.samples.load(Ordering::Relaxed),
+            detect_to_dispatch: Percentiles::from_histogram(&self.detect_to_dispatch_us.lock()),
+            dispatch_to_ack: Percentiles::from_histogram(&self.dispatch_to_ack_us.lock()),
+            end_to_end: Percentiles::from_histogram(&self.end_to_end_us.lock()),
+        }
+    }
+}
+
+/// Latency breakdown of the fill-detection -> hedge pipeline, bucketed by
+/// `FillSource` so the 500ms REST backup poller's extra latency shows up
+/// distinctly from the WebSocket steady state instead of being averaged away.
+///
+/// Tracks three spans per source, all measured off the fill's detection
+/// timestamp (`HedgeChunk::detected_at` / `HedgeEvent`'s `fill_detect_timestamp`):
+/// - `detect_to_dispatch`: time from detection until `HedgeService` picks the
+///   fill up off `fill_rx`
+/// - `dispatch_to_ack`: time for a dispatched taker hedge order to come back
+///   with a successful exchange response; attempts that return an error are
+///   not recorded
+/// - `end_to_end`: detection until the hedge is fully settled
+///
+/// Uses `hdrhistogram::Histogram` behind a `parking_lot::Mutex`, the same
+/// pairing `OrderMonitorService::HotPathProfiler` uses, rather than
+/// `crate::metrics::LatencyHistogram`'s fixed Prometheus buckets - this is
+/// read via `snapshot`/`log_summary` rather than scraped, so exact bounded-
+/// memory quantiles are a better fit than pre-declared bucket boundaries.
+pub struct FillLatencyProfiler {
+    websocket: SourceLatency,
+    rest: SourceLatency,
+}
+
+impl FillLatencyProfiler {
+    pub fn new() -> Self {
+        Self {
+            websocket: SourceLatency::new(),
+            rest: SourceLatency::new(),
+        }
+    }
+
+    fn source_latency(&self, source: FillSource) -> &SourceLatency {
+        match source {
+            FillSource::WebSocket => &self.websocket,
+            FillSource::Rest => &self.rest,
+        }
+    }
+
+    /// Record how long a detected fill waited before `HedgeService` started
+    /// driving it to completion
+    pub fn record_detect_to_dispatch(&self, source: FillSource, elapsed: Duration) {
+        let latency = self.source_latency(source);
+        SourceLatency::record(&latency.detect_to_dispatch_us, elapsed);
+        latency.samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record how long one taker hedge attempt took to come back with a
+    /// successful exchange response
+    pub fn record_dispatch_to_ack(&self, source: FillSource, elapsed: Duration) {
+        SourceLatency::record(&self.source_latency(source).dispatch_to_ack_us, elapsed);
+    }
+
+    /// Record the full fill-detection-to-hedge-complete span
+    pub fn record_end_to_end(&self, source: FillSource, elapsed: Duration) {
+        SourceLatency::record(&self.source_latency(source).end_to_end_us, elapsed);
+    }
+
+    /// A queryable snapshot of both sources' current percentiles, for a
+    /// caller that wants the numbers directly (a future `/metrics` field, a
+    /// dashboard query) rather than the periodic log line
+    pub fn snapshot(&self) -> Vec<FillLatencySnapshot> {
+        vec![
+            self.websocket.snapshot(FillSource::WebSocket),
+            self.rest.snapshot(FillSource::Rest),
+        ]
+    }
+
+    /// Log a one-line percentile summary per source that has recorded at
+    /// least one sample - called from a periodic reporter task the same way
+    /// `OrderMonitorService::run_latency_reporter` logs `HotPathProfiler`
+    pub fn log_summary(&self) {
+        for snapshot in self.snapshot() {
+            if snapshot.samples == 0 {
+                continue;
+            }
+            info!(
+                "[FILL_LATENCY] {} ({} samples): detect->dispatch p50={}us p90={}us p99={}us | dispatch->ack p50={}us p90={}us p99={}us | end-to-end p50={}us p90={}us p99={}us",
+                snapshot.source.as_str(),
+                snapshot.samples,
+                snapshot.detect_to_dispatch.p50_us,
+                snapshot.detect_to_dispatch.p90_us,
+                snapshot.detect_to_dispatch.p99_us,
+                snapshot.dispatch_to_ack.p50_us,
+                snapshot.dispatch_to_ack.p90_us,
+                snapshot.dispatch_to_ack.p99_us,
+                snapshot.end_to_end.p50_us,
+                snapshot.end_to_end.p90_us,
+                snapshot.end_to_end.p99_us,
+            );
+        }
+    }
+}
+
+impl Default for FillLatencyProfiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
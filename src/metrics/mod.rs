@@ -0,0 +1,161 @@
+/// Metrics module - latency histograms and counters for the critical paths,
+/// exposed over HTTP in Prometheus text-exposition format
+
+pub mod fill_latency;
+pub mod server;
+
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub use fill_latency::{FillLatencyProfiler, FillLatencySnapshot, Percentiles};
+pub use server::MetricsServer;
+
+/// Log-spaced bucket upper bounds in milliseconds, covering 1ms..10s
+const LATENCY_BUCKETS_MS: &[f64] = &[
+    1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0, 10000.0,
+];
+
+/// A fixed, log-spaced bucket latency histogram in the Prometheus sense: each
+/// bucket counts observations `<= le`, plus a running count/sum so operators
+/// (or a Prometheus `histogram_quantile`) can estimate percentiles.
+#[derive(Debug)]
+pub struct LatencyHistogram {
+    name: &'static str,
+    help: &'static str,
+    bucket_counts: Vec<AtomicU64>,
+    count: AtomicU64,
+    sum_micros: AtomicU64,
+}
+
+impl LatencyHistogram {
+    fn new(name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            help,
+            bucket_counts: LATENCY_BUCKETS_MS.iter().map(|_| AtomicU64::new(0)).collect(),
+            count: AtomicU64::new(0),
+            sum_micros: AtomicU64::new(0),
+        }
+    }
+
+    /// Record one observation of `elapsed`
+    pub fn observe(&self, elapsed: Duration) {
+        let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+        for (bound, bucket) in LATENCY_BUCKETS_MS.iter().zip(self.bucket_counts.iter()) {
+            if elapsed_ms <= *bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        self.sum_micros.fetch_add(elapsed.as_micros().min(u64::MAX as u128) as u64, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn render(&self, out: &mut String) {
+        let _ = writeln!(out, "# HELP {} {}", self.name, self.help);
+        let _ = writeln!(out, "# TYPE {} histogram", self.name);
+        for (bound, bucket) in LATENCY_BUCKETS_MS.iter().zip(self.bucket_counts.iter()) {
+            let _ = writeln!(out, "{}_bucket{{le=\"{}\"}} {}", self.name, bound, bucket.load(Ordering::Relaxed));
+        }
+        let count = self.count.load(Ordering::Relaxed);
+        let _ = writeln!(out, "{}_bucket{{le=\"+Inf\"}} {}", self.name, count);
+        let _ = writeln!(out, "{}_sum {}", self.name, self.sum_micros.load(Ordering::Relaxed) as f64 / 1000.0);
+        let _ = writeln!(out, "{}_count {}", self.name, count);
+    }
+}
+
+/// Shared observability sink for the bot's critical paths. Cheap to clone
+/// (wrap in `Arc`) and safe to record into from any task; recording is
+/// lock-free except for `fill_latency`, whose histograms sit behind
+/// `parking_lot` mutexes.
+pub struct Metrics {
+    /// Round-trip latency of placing a maker order on Pacifica
+    pub maker_order_placement_latency: LatencyHistogram,
+    /// Delay between detecting a Pacifica fill and sending it down `hedge_tx`
+    pub fill_to_hedge_latency: LatencyHistogram,
+    /// Time for the Hyperliquid taker hedge order to fill once submitted
+    pub hedge_fill_latency: LatencyHistogram,
+    /// Fill-detection -> hedge pipeline latency, broken out by `FillSource`
+    /// (WebSocket vs REST) and by stage (detect->dispatch, dispatch->ack,
+    /// end-to-end), with exact queryable quantiles rather than fixed buckets
+    pub fill_latency: FillLatencyProfiler,
+    fills_processed: AtomicU64,
+    hedges_succeeded: AtomicU64,
+    hedges_failed: AtomicU64,
+    /// Cumulative realized spread across closed cycles, in bps * 1000 (fixed-point, can go negative)
+    realized_spread_bps_sum_milli: AtomicI64,
+}
+
+impl Metrics {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            maker_order_placement_latency: LatencyHistogram::new(
+                "xemm_maker_order_placement_latency_ms",
+                "Round-trip latency of placing a maker order on Pacifica",
+            ),
+            fill_to_hedge_latency: LatencyHistogram::new(
+                "xemm_fill_to_hedge_send_latency_ms",
+                "Delay between detecting a maker fill and sending it down hedge_tx",
+            ),
+            hedge_fill_latency: LatencyHistogram::new(
+                "xemm_hedge_fill_latency_ms",
+                "Time for the Hyperliquid taker hedge order to fill",
+            ),
+            fill_latency: FillLatencyProfiler::new(),
+            fills_processed: AtomicU64::new(0),
+            hedges_succeeded: AtomicU64::new(0),
+            hedges_failed: AtomicU64::new(0),
+            realized_spread_bps_sum_milli: AtomicI64::new(0),
+        })
+    }
+
+    /// Record that a maker fill was processed
+    pub fn record_fill(&self) {
+        self.fills_processed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record the outcome of a hedge attempt
+    pub fn record_hedge_result(&self, succeeded: bool) {
+        if succeeded {
+            self.hedges_succeeded.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.hedges_failed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Fold a closed cycle's realized spread (in bps) into the running total
+    pub fn record_realized_spread_bps(&self, spread_bps: f64) {
+        self.realized_spread_bps_sum_milli.fetch_add((spread_bps * 1000.0) as i64, Ordering::Relaxed);
+    }
+
+    /// Render every histogram and counter in Prometheus text-exposition format
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+        self.maker_order_placement_latency.render(&mut out);
+        self.fill_to_hedge_latency.render(&mut out);
+        self.hedge_fill_latency.render(&mut out);
+
+        let _ = writeln!(out, "# HELP xemm_fills_processed_total Maker fills observed");
+        let _ = writeln!(out, "# TYPE xemm_fills_processed_total counter");
+        let _ = writeln!(out, "xemm_fills_processed_total {}", self.fills_processed.load(Ordering::Relaxed));
+
+        let _ = writeln!(out, "# HELP xemm_hedges_succeeded_total Hedge orders that filled successfully");
+        let _ = writeln!(out, "# TYPE xemm_hedges_succeeded_total counter");
+        let _ = writeln!(out, "xemm_hedges_succeeded_total {}", self.hedges_succeeded.load(Ordering::Relaxed));
+
+        let _ = writeln!(out, "# HELP xemm_hedges_failed_total Hedge orders that failed or were aborted");
+        let _ = writeln!(out, "# TYPE xemm_hedges_failed_total counter");
+        let _ = writeln!(out, "xemm_hedges_failed_total {}", self.hedges_failed.load(Ordering::Relaxed));
+
+        let _ = writeln!(
+            out,
+            "# HELP xemm_realized_spread_bps_sum Cumulative realized spread across closed cycles, in basis points"
+        );
+        let _ = writeln!(out, "# TYPE xemm_realized_spread_bps_sum gauge");
+        let spread_bps = self.realized_spread_bps_sum_milli.load(Ordering::Relaxed) as f64 / 1000.0;
+        let _ = writeln!(out, "xemm_realized_spread_bps_sum {}", spread_bps);
+
+        out
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use super::Metrics;
+
+// Macro for timestamped colored output
+macro_rules! tprintln {
+    ($($arg:tt)*) => {{
+        println!("{} {}",
+            chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string().bright_black(),
+            format!($($arg)*)
+        );
+    }};
+}
+
+/// Serves `Metrics::render_prometheus` on `GET /metrics`
+///
+/// Hand-rolled HTTP/1.1 responder rather than a full web framework - this is
+/// a single read-only endpoint polled by a scraper every few seconds, not a
+/// general-purpose API surface.
+pub struct MetricsServer {
+    pub metrics: Arc<Metrics>,
+    pub port: u16,
+}
+
+impl MetricsServer {
+    pub async fn run(self) -> Result<()> {
+        let listener = TcpListener::bind(("0.0.0.0", self.port))
+            .await
+            .with_context(|| format!("Failed to bind metrics server on port {}", self.port))?;
+        tprintln!(
+            "{} Serving Prometheus metrics on :{}/metrics",
+            "[METRICS]".magenta().bold(),
+            self.port
+        );
+
+        loop {
+            let (mut socket, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    tprintln!("{} Failed to accept metrics connection: {}", "[METRICS]".red().bold(), e);
+                    continue;
+                }
+            };
+            let metrics = self.metrics.clone();
+            tokio::spawn(async move {
+                let mut buf = [0u8; 1024];
+                // We only serve one fixed response regardless of path/method, so the
+                // request itself just needs draining, not parsing.
+                if socket.read(&mut buf).await.is_err() {
+                    return;
+                }
+                let body = metrics.render_prometheus();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{debug, info, warn};
+
+use crate::config::Config;
+use crate::connector::pacifica::PacificaWsTrading;
+
+// ============================================================================
+// FILL-DETECTION MODE (WEBSOCKET vs REST)
+// ============================================================================
+
+/// Which fill-detection layer is currently authoritative, so the hedge path
+/// and logs can reflect it instead of assuming WebSocket is always live.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectionMode {
+    WebSocket = 0,
+    Rest = 1,
+}
+
+impl From<u8> for DetectionMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => DetectionMode::WebSocket,
+            _ => DetectionMode::Rest,
+        }
+    }
+}
+
+// ============================================================================
+// CONNECTIVITY SUPERVISOR
+// ============================================================================
+
+/// Periodically health-checks `PacificaWsTrading` and flips the shared
+/// detection-mode atomic between `WebSocket` and `Rest` accordingly, so
+/// resilience against a dead socket doesn't require hand-disabling WS the way
+/// `examples/test_rest_fill_detection.rs` does.
+///
+/// On a stale/disconnected socket: escalates REST fill detection (exposed via
+/// `mode_handle` for `rest_poll`/`rest_fill_detection` to read, and consumed
+/// directly by `OrderMonitorService::run_fill_poller` today, since that's the
+/// only REST fill-detection path that exists in this tree) from its slow
+/// heartbeat cadence to the aggressive 500ms polling the test simulates, and
+/// attempts reconnection with exponential backoff. De-escalates REST back to
+/// the heartbeat cadence once the socket is confirmed live again.
+pub struct ConnectivitySupervisor {
+    pacifica_ws_trading: Arc<PacificaWsTrading>,
+    config: Config,
+    mode: Arc<AtomicU8>,
+}
+
+impl ConnectivitySupervisor {
+    pub fn new(pacifica_ws_trading: Arc<PacificaWsTrading>, config: Config) -> Self {
+        Self {
+            pacifica_ws_trading,
+            config,
+            mode: Arc::new(AtomicU8::new(DetectionMode::WebSocket as u8)),
+        }
+    }
+
+    /// Cheap, lock-free handle other services can hold to read the current
+    /// detection mode without going through the supervisor itself
+    pub fn mode_handle(&self) -> Arc<AtomicU8> {
+        self.mode.clone()
+    }
+
+    pub fn current_mode(&self) -> DetectionMode {
+        DetectionMode::from(self.mode.load(Ordering::Acquire))
+    }
+
+    /// Health-check loop - runs until the process exits
+    pub async fn run(&self) {
+        let mut check_interval = tokio::time::interval(Duration::from_secs(self.config.ws_health_check_interval_secs));
+        let mut reconnect_attempt: u32 = 0;
+
+        loop {
+            check_interval.tick().await;
+
+            if self.check_liveness().await {
+                reconnect_attempt = 0;
+                if self.current_mode() == DetectionMode::Rest {
+                    info!("[CONNECTIVITY] Pacifica WS recovered - de-escalating REST fill detection back to heartbeat cadence");
+                    self.mode.store(DetectionMode::WebSocket as u8, Ordering::Release);
+                }
+                continue;
+            }
+
+            if self.current_mode() == DetectionMode::WebSocket {
+                warn!(
+                    "[CONNECTIVITY] Pacifica WS stale/disconnected - escalating REST fill detection to {}ms polling",
+                    self.config.rest_poll_aggressive_interval_ms
+                );
+                self.mode.store(DetectionMode::Rest as u8, Ordering::Release);
+            }
+
+            reconnect_attempt += 1;
+            match self.pacifica_ws_trading.reconnect().await {
+                Ok(()) => {
+                    info!(
+                        "[CONNECTIVITY] Pacifica WS reconnect attempt #{} succeeded - confirming liveness next check",
+                        reconnect_attempt
+                    );
+                }
+                Err(e) => {
+                    let backoff = reconnect_backoff(
+                        reconnect_attempt,
+                        self.config.ws_reconnect_base_backoff_ms,
+                        self.config.ws_reconnect_max_backoff_secs,
+                    );
+                    warn!(
+                        "[CONNECTIVITY] Pacifica WS reconnect attempt #{} failed: {} - retrying in {:?}",
+                        reconnect_attempt, e, backoff
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+            }
+        }
+    }
+
+    /// A socket is only considered live if it's both produced a message
+    /// recently and answers a fresh ping/pong - a half-open connection can
+    /// satisfy one of these while silently failing the other (still ACKing
+    /// pings while the subscription feed has gone quiet, or vice versa).
+    async fn check_liveness(&self) -> bool {
+        let stale_after = Duration::from_secs(self.config.ws_stale_after_secs);
+        match self.pacifica_ws_trading.last_message_at() {
+            Some(last_message_at) if last_message_at.elapsed() <= stale_after => {}
+            Some(last_message_at) => {
+                debug!("[CONNECTIVITY] Pacifica WS last message {:?} ago exceeds {:?} staleness budget", last_message_at.elapsed(), stale_after);
+                return false;
+            }
+            None => {
+                debug!("[CONNECTIVITY] Pacifica WS has never received a message");
+                return false;
+            }
+        }
+
+        match tokio::time::timeout(Duration::from_secs(2), self.pacifica_ws_trading.ping()).await {
+            Ok(Ok(())) => true,
+            Ok(Err(e)) => {
+                debug!("[CONNECTIVITY] Pacifica WS ping failed: {}", e);
+                false
+            }
+            Err(_) => {
+                debug!("[CONNECTIVITY] Pacifica WS ping timed out");
+                false
+            }
+        }
+    }
+}
+
+/// Exponential backoff for reconnect attempt `attempt` (1-indexed), doubling
+/// `base_ms` each attempt and capped at `max_secs` - pulled out of `run`'s
+/// loop body as a pure function so the growth/cap math is testable without a
+/// live `PacificaWsTrading` to drive the loop itself.
+fn reconnect_backoff(attempt: u32, base_ms: u64, max_secs: u64) -> Duration {
+    Duration::from_millis(base_ms * 2u64.pow(attempt.min(6))).min(Duration::from_secs(max_secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detection_mode_from_u8_roundtrip() {
+        assert_eq!(DetectionMode::from(0u8), DetectionMode::WebSocket);
+        // Anything nonzero is treated as Rest, not just the canonical 1
+        assert_eq!(DetectionMode::from(1u8), DetectionMode::Rest);
+        assert_eq!(DetectionMode::from(255u8), DetectionMode::Rest);
+    }
+
+    #[test]
+    fn test_reconnect_backoff_doubles_each_attempt() {
+        assert_eq!(reconnect_backoff(1, 100, 60), Duration::from_millis(200));
+        assert_eq!(reconnect_backoff(2, 100, 60), Duration::from_millis(400));
+        assert_eq!(reconnect_backoff(3, 100, 60), Duration::from_millis(800));
+    }
+
+    #[test]
+    fn test_reconnect_backoff_is_capped_at_max_secs() {
+        // 2^10 * 100ms would be ~102s uncapped; attempt is clamped to 6 (2^6 = 64x)
+        // and the result is further capped at max_secs
+        assert_eq!(reconnect_backoff(10, 100, 5), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_reconnect_backoff_exponent_clamped_at_six() {
+        // Attempt 6 and attempt 20 should produce the same pre-cap growth
+        let at_six = reconnect_backoff(6, 10, 3600);
+        let at_twenty = reconnect_backoff(20, 10, 3600);
+        assert_eq!(at_six, at_twenty);
+    }
+}
@@ -0,0 +1,216 @@
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+use crate::strategy::OrderSide;
+
+// ============================================================================
+// UNIFIED FILL DEDUPLICATION (SHARED ACROSS FILL-DETECTION LAYERS)
+// ============================================================================
+
+/// Which layer observed a fill report - recorded for telemetry only, it never
+/// affects dedup. A fill seen first by REST and then by WebSocket (or vice
+/// versa) is the same underlying event as far as `FillLedger` is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillSource {
+    WebSocket,
+    Rest,
+}
+
+impl FillSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FillSource::WebSocket => "websocket",
+            FillSource::Rest => "rest",
+        }
+    }
+}
+
+/// One fill report from either detection layer. `cumulative_filled` is the
+/// total filled size reported so far for `cloid` (matching how exchanges
+/// report fills on `get_open_orders`/order-update streams), not a per-report
+/// delta - `FillLedger` is what turns it into a delta.
+#[derive(Debug, Clone)]
+pub struct FillEvent {
+    pub cloid: String,
+    pub seq: u64,
+    pub cumulative_filled: f64,
+    pub avg_price: f64,
+    pub side: OrderSide,
+    pub source: FillSource,
+    pub detect_ts: Instant,
+}
+
+/// Shared dedup point for every fill-detection layer (WebSocket, REST, or any
+/// future one), keyed by `cloid`.
+///
+/// Each layer reports the *cumulative* filled amount it observed; the ledger
+/// tracks the max cumulative value seen per order and only emits the delta
+/// `new_cumulative - max_seen`. A report whose cumulative is <= the current
+/// max is dropped - this is what makes a fill seen by both layers (regardless
+/// of which arrives first) hedge exactly once, and an out-of-order or
+/// replayed report a no-op, without either layer needing its own ad-hoc
+/// dedup set.
+///
+/// The original request asks to unify dedup across "`fill_detection`
+/// (WebSocket) and `rest_fill_detection` (REST)", replacing the per-service
+/// `HashSet` logic in both. Neither of those two modules exists in this
+/// snapshot - `OrderMonitorService`'s REST fill poller
+/// (`poll_and_hedge_delta` in `order_monitor.rs`) is the only fill-detection
+/// path in this tree, and is the only thing wired into this ledger
+/// (`self.fill_ledger.record(...)`, gating `BotState::mark_filled`).
+///
+/// So as delivered, `FillLedger` has exactly one consumer, not the two the
+/// request describes - there's nothing for it to unify yet. NEEDS REQUESTER
+/// INPUT: confirm whether a WebSocket fill-detection layer is still planned
+/// (in which case this ledger is the right shared dedup point for it once it
+/// exists) or whether REST-only fill detection is the accepted final scope
+/// for this chunk.
+pub struct FillLedger {
+    max_seen: DashMap<String, f64>,
+}
+
+impl FillLedger {
+    pub fn new() -> Self {
+        Self { max_seen: DashMap::new() }
+    }
+
+    /// Record a fill report and return the newly-filled delta to hedge, or
+    /// `None` if this report's cumulative amount doesn't exceed what's
+    /// already been seen for this `cloid` (a duplicate, a replay from the
+    /// other layer, or a stale out-of-order report).
+    pub fn record(&self, event: FillEvent) -> Option<f64> {
+        let mut max_entry = self.max_seen.entry(event.cloid.clone()).or_insert(0.0);
+        if event.cumulative_filled <= *max_entry {
+            return None;
+        }
+        let delta = event.cumulative_filled - *max_entry;
+        *max_entry = event.cumulative_filled;
+        Some(delta)
+    }
+
+    /// Drop the ledger entry for `cloid` once its order has reached a
+    /// terminal state, so a future `client_order_id` collision (vanishingly
+    /// unlikely, but not impossible) can't inherit a stale max.
+    pub fn forget(&self, cloid: &str) {
+        self.max_seen.remove(cloid);
+    }
+
+    /// Pre-populate the max-seen cumulative fill for `cloid` from a durable
+    /// record (e.g. `PostgresStorageService::backfill_since` replaying rows
+    /// persisted before a crash), so a freshly restarted process doesn't
+    /// re-emit deltas - and re-hedge - for fills it already processed. A
+    /// no-op if `cumulative_filled` isn't greater than whatever's already
+    /// recorded for `cloid`, so seeding in any order is safe.
+    pub fn seed(&self, cloid: &str, cumulative_filled: f64) {
+        let mut max_entry = self.max_seen.entry(cloid.to_string()).or_insert(0.0);
+        if cumulative_filled > *max_entry {
+            *max_entry = cumulative_filled;
+        }
+    }
+}
+
+impl Default for FillLedger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// PARTIAL-FILL ACCUMULATION (BATCH SMALL FILLS INTO SIZED HEDGE CHUNKS)
+// ============================================================================
+
+/// A batched chunk of accumulated fill deltas ready to dispatch as one hedge,
+/// with `avg_price` as the volume-weighted average of the deltas that make it
+/// up rather than any single fill's price.
+pub struct HedgeChunk {
+    pub side: OrderSide,
+    pub size: f64,
+    pub avg_price: f64,
+    /// When the chunk's first unhedged delta was observed - the
+    /// fill-detection timestamp latency metrics measure dispatch latency
+    /// against, since that's the moment the exposure this chunk hedges
+    /// actually appeared, not when the chunk happened to cross a dispatch
+    /// threshold.
+    pub detected_at: Instant,
+}
+
+struct PendingChunk {
+    side: OrderSide,
+    size: f64,
+    notional_sum: f64,
+    first_unhedged_at: Instant,
+}
+
+/// Batches fill deltas for one `cloid` into a pending-but-unhedged chunk
+/// instead of forwarding every individual delta as its own hedge leg, which
+/// on a thinly-filled resting order can otherwise fire a storm of tiny hedges
+/// with poor fees/slippage.
+///
+/// A chunk dispatches on whichever of these comes first:
+/// 1. its accumulated notional crosses `chunk_notional_usd`,
+/// 2. the order fully fills, or
+/// 3. `max_latency` has elapsed since its first unhedged delta, checked only
+///    when the next delta for the same `cloid` arrives,
+///
+/// bounding hedge size. There is no timer: a pending chunk that receives no
+/// further delta is dispatched only by `force_flush`.
+pub struct FillAccumulator {
+    pending: DashMap<String, PendingChunk>,
+    chunk_notional_usd: f64,
+    max_latency: Duration,
+}
+
+impl FillAccumulator {
+    pub fn new(chunk_notional_usd: f64, max_latency: Duration) -> Self {
+        Self {
+            pending: DashMap::new(),
+            chunk_notional_usd,
+            max_latency,
+        }
+    }
+
+    /// Fold `delta` units of `side`, filled at `price`, into `cloid`'s
+    /// pending chunk. Returns the chunk to hedge now - with its
+    /// volume-weighted average price - if a dispatch condition is met,
+    /// clearing the pending state for `cloid`; otherwise returns `None` and
+    /// keeps accumulating.
+    pub fn accumulate(&self, cloid: &str, side: OrderSide, delta: f64, price: f64, is_full_fill: bool) -> Option<HedgeChunk> {
+        if delta <= 0.0 {
+            return None;
+        }
+
+        let should_dispatch = {
+            let mut entry = self.pending.entry(cloid.to_string()).or_insert_with(|| PendingChunk {
+                side,
+                size: 0.0,
+                notional_sum: 0.0,
+                first_unhedged_at: Instant::now(),
+            });
+            entry.size += delta;
+            entry.notional_sum += delta * price;
+
+            is_full_fill || entry.notional_sum.abs() >= self.chunk_notional_usd || entry.first_unhedged_at.elapsed() >= self.max_latency
+        };
+
+        if should_dispatch { self.force_flush(cloid) } else { None }
+    }
+
+    /// Pull whatever is currently pending for `cloid` regardless of dispatch
+    /// thresholds, clearing its pending state - used when an order is being
+    /// cancelled or otherwise leaving the book and any accumulated-but-unsent
+    /// quantity needs to go out now rather than wait for a chunk/latency
+    /// trigger that may never come.
+    pub fn force_flush(&self, cloid: &str) -> Option<HedgeChunk> {
+        let (_, chunk) = self.pending.remove(cloid)?;
+        if chunk.size <= 0.0 {
+            return None;
+        }
+        Some(HedgeChunk {
+            side: chunk.side,
+            size: chunk.size,
+            avg_price: chunk.notional_sum / chunk.size,
+            detected_at: chunk.first_unhedged_at,
+        })
+    }
+}
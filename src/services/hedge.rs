@@ -0,0 +1,419 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use tokio::sync::{mpsc, RwLock};
+use tracing::{error, info, warn};
+
+use crate::bot::execution::{ExecutableMatch, HedgeAction, HedgeState};
+use crate::bot::BotState;
+use crate::config::Config;
+use crate::connector::hyperliquid::HyperliquidTrading;
+use crate::connector::pacifica::{OrderSide as PacificaOrderSide, PacificaTrading};
+use crate::metrics::Metrics;
+use crate::services::fill_ledger::FillSource;
+use crate::services::hedge_feed::HedgeFeedPublisher;
+use crate::services::orderbook::{check_hedge_epoch_freshness, TimestampedPrice};
+use crate::storage::Storage;
+use crate::strategy::{OpportunityEvaluator, OrderSide};
+
+/// A maker fill handed from fill detection into the hedge path: the filled
+/// side/size/average price, the Pacifica/Hyperliquid price epochs captured
+/// at fill time so `check_hedge_epoch_freshness` can catch a quote that has
+/// since moved out from under the fill, when the fill was first detected
+/// (for `Metrics::fill_latency`), and which layer detected it.
+pub type FillEvent = (OrderSide, f64, f64, u64, u64, Instant, FillSource);
+
+/// Drives a single maker fill through the optimistic hedge path to one of two
+/// terminal outcomes - fully hedged, or fully rolled back - never a dangling
+/// one-sided exposure.
+///
+/// Modeled as a two-phase commit over `ExecutableMatch`: submit the opposite
+/// taker order on Hyperliquid assuming it fills cleanly, retry with widened
+/// slippage on a short or failed attempt, and once retries are exhausted,
+/// roll back per `config.hedge_rollback_mode`: under
+/// `RollbackMode::WidenedCross` make one last widened-cross attempt first, and
+/// if that also comes up short (or straight away under
+/// `RollbackMode::FlattenOnPacifica`), flatten the naked Pacifica leg with a
+/// market order and surface the outcome loudly rather than leave the position
+/// unmanaged.
+pub struct HedgeService {
+    pub symbol: String,
+    pub bot_state: Arc<RwLock<BotState>>,
+    pub config: Config,
+    pub evaluator: OpportunityEvaluator,
+    pub hyperliquid_trading: Arc<HyperliquidTrading>,
+    pub pacifica_trading: Arc<PacificaTrading>,
+    pub pacifica_prices: Arc<Mutex<TimestampedPrice>>,
+    pub hyperliquid_prices: Arc<Mutex<TimestampedPrice>>,
+    pub metrics: Arc<Metrics>,
+    pub store: Arc<Storage>,
+    /// Publishes incremental fill/hedge/unwind events plus the running
+    /// net-position reference state to any connected `HedgeFeedServer`
+    /// clients - purely observational, never read back to drive decisions
+    pub hedge_feed: Arc<HedgeFeedPublisher>,
+    pub fill_rx: mpsc::Receiver<FillEvent>,
+}
+
+impl HedgeService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        symbol: String,
+        bot_state: Arc<RwLock<BotState>>,
+        config: Config,
+        evaluator: OpportunityEvaluator,
+        hyperliquid_trading: Arc<HyperliquidTrading>,
+        pacifica_trading: Arc<PacificaTrading>,
+        pacifica_prices: Arc<Mutex<TimestampedPrice>>,
+        hyperliquid_prices: Arc<Mutex<TimestampedPrice>>,
+        metrics: Arc<Metrics>,
+        store: Arc<Storage>,
+        hedge_feed: Arc<HedgeFeedPublisher>,
+        fill_rx: mpsc::Receiver<FillEvent>,
+    ) -> Self {
+        Self {
+            symbol,
+            bot_state,
+            config,
+            evaluator,
+            hyperliquid_trading,
+            pacifica_trading,
+            pacifica_prices,
+            hyperliquid_prices,
+            metrics,
+            store,
+            hedge_feed,
+            fill_rx,
+        }
+    }
+
+    /// Consume fill events off `fill_rx` until the channel closes, driving
+    /// each one to completion in turn (fills are serialized so a bot never
+    /// has two hedges racing over the same `ActiveOrder`/`BotState`)
+    pub async fn run(mut self) {
+        while let Some(fill) = self.fill_rx.recv().await {
+            self.drive_to_completion(fill).await;
+        }
+    }
+
+    async fn drive_to_completion(&self, fill: FillEvent) {
+        let (side, size, maker_avg_price, pacifica_epoch, hyperliquid_epoch, fill_detect_timestamp, fill_source) = fill;
+        self.metrics.record_fill();
+        self.metrics
+            .fill_latency
+            .record_detect_to_dispatch(fill_source, fill_detect_timestamp.elapsed());
+        self.hedge_feed.record_fill_detected(&self.symbol, side, size, maker_avg_price);
+
+        // This channel doesn't carry the Pacifica client_order_id, so the
+        // captured epochs double as a label unique enough for logs and the
+        // hedges table's foreign key; refine once fill detection threads the
+        // client_order_id through `hedge_tx` instead.
+        let fill_id = format!("{}-{}-{}", self.symbol, pacifica_epoch, hyperliquid_epoch);
+        let hedge_side = match side {
+            OrderSide::Buy => OrderSide::Sell,
+            OrderSide::Sell => OrderSide::Buy,
+        };
+
+        let mut executable = ExecutableMatch::new(fill_id.clone(), side, size, maker_avg_price);
+        let max_age = Duration::from_secs(self.config.order_refresh_interval_secs);
+
+        loop {
+            let pac_quote = self.pacifica_prices.lock().clone();
+            let hl_quote = self.hyperliquid_prices.lock().clone();
+            if !check_hedge_epoch_freshness(pacifica_epoch, &pac_quote, self.config.hedge_epoch_tolerance, max_age)
+                || !check_hedge_epoch_freshness(hyperliquid_epoch, &hl_quote, self.config.hedge_epoch_tolerance, max_age)
+            {
+                warn!(
+                    "[HEDGE] {} quote view is stale relative to the fill - submitting anyway, there's nothing fresher to wait for",
+                    fill_id
+                );
+            }
+
+            executable.begin_hedging();
+            self.bot_state.write().await.mark_hedging();
+
+            let attempt = match executable.hedge_state {
+                HedgeState::Hedging { attempt } => attempt,
+                _ => 1,
+            };
+            // Widen allowed slippage proportionally to the retry count so a
+            // repeatedly-short hedge eventually clears even a thin book
+            let slippage = self.config.hyperliquid_slippage * attempt as f64;
+
+            let hedge_started = Instant::now();
+            let hedge_result = self
+                .hyperliquid_trading
+                .place_market_order(&self.symbol, hedge_side, executable.residual_size, slippage)
+                .await;
+
+            let action = match hedge_result {
+                Ok((filled_size, hedge_avg_price)) if filled_size >= executable.residual_size - f64::EPSILON => {
+                    self.metrics.hedge_fill_latency.observe(hedge_started.elapsed());
+                    self.metrics.fill_latency.record_dispatch_to_ack(fill_source, hedge_started.elapsed());
+                    executable.record_hedge_fill(filled_size, hedge_avg_price);
+                    None
+                }
+                Ok((filled_size, hedge_avg_price)) => {
+                    self.metrics.hedge_fill_latency.observe(hedge_started.elapsed());
+                    self.metrics.fill_latency.record_dispatch_to_ack(fill_source, hedge_started.elapsed());
+                    executable.record_hedge_fill(filled_size, hedge_avg_price);
+                    Some(executable.next_action(self.config.max_hedge_retries, self.config.hedge_rollback_mode, "hedge order filled short"))
+                }
+                Err(e) => Some(executable.next_action(self.config.max_hedge_retries, self.config.hedge_rollback_mode, e.to_string())),
+            };
+
+            match action {
+                None => {
+                    self.finish_hedged(&executable, side, maker_avg_price, fill_source, fill_detect_timestamp).await;
+                    return;
+                }
+                Some(HedgeAction::RetryWithWidenedSlippage { residual_size }) => {
+                    warn!(
+                        "[HEDGE] {} retrying with widened slippage ({:.3}%), residual {}",
+                        fill_id,
+                        slippage * 100.0,
+                        residual_size
+                    );
+                    continue;
+                }
+                Some(HedgeAction::RollbackWidenedCross { residual_size }) => {
+                    self.rollback_widened_cross(
+                        &fill_id,
+                        &mut executable,
+                        hedge_side,
+                        side,
+                        maker_avg_price,
+                        residual_size,
+                        fill_source,
+                        fill_detect_timestamp,
+                    )
+                    .await;
+                    return;
+                }
+                Some(HedgeAction::UnwindOnPacifica { residual_size }) => {
+                    self.unwind(&fill_id, side, maker_avg_price, residual_size).await;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Record a fully hedged fill: fold the realized spread into `Storage`
+    /// and `Metrics`, then hand it to `BotState::finish_hedge` - which only
+    /// closes the cycle (`Complete`, then back to `Idle`) once this was the
+    /// order's last unhedged piece, and otherwise just reports the order's
+    /// current fill state
+    async fn finish_hedged(
+        &self,
+        executable: &ExecutableMatch,
+        side: OrderSide,
+        maker_avg_price: f64,
+        fill_source: FillSource,
+        fill_detect_timestamp: Instant,
+    ) {
+        let hedge_avg_price = match executable.hedge_state {
+            HedgeState::Hedged { hedge_avg_price } => hedge_avg_price,
+            _ => maker_avg_price,
+        };
+
+        let realized_spread_bps =
+            self.evaluator
+                .recalculate_profit_raw(side, maker_avg_price, hedge_avg_price, hedge_avg_price);
+
+        self.metrics.record_hedge_result(true);
+        self.metrics.record_realized_spread_bps(realized_spread_bps);
+        self.metrics
+            .fill_latency
+            .record_end_to_end(fill_source, fill_detect_timestamp.elapsed());
+        let hedge_side = match side {
+            OrderSide::Buy => OrderSide::Sell,
+            OrderSide::Sell => OrderSide::Buy,
+        };
+        self.hedge_feed
+            .record_hedge_settled(&self.symbol, hedge_side, executable.size, hedge_avg_price, realized_spread_bps);
+
+        let hedged_at_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        if let Err(e) = self.store.record_hedge(
+            &executable.fill_id,
+            &self.symbol,
+            hedge_avg_price,
+            executable.size,
+            realized_spread_bps,
+            0.0, // Funding carry isn't attributed per-hedge yet; tracked in aggregate elsewhere
+            hedged_at_ms,
+        ) {
+            warn!("[HEDGE] {} failed to persist hedge record: {}", executable.fill_id, e);
+        }
+
+        let record = {
+            let mut state = self.bot_state.write().await;
+            // `ActiveOrder` only carries a monotonic `placed_at`, not an epoch
+            // timestamp, so derive `opened_at_ms` from how long ago it was
+            // placed relative to `hedged_at_ms` rather than threading a
+            // separate wall-clock placement time through the hedge path
+            let opened_at_ms = state
+                .active_order
+                .as_ref()
+                .map(|o| hedged_at_ms.saturating_sub(o.placed_at.elapsed().as_millis() as u64))
+                .unwrap_or(hedged_at_ms);
+
+            // `finish_hedge` only returns `Some` once this was the last
+            // unhedged piece of a fully-filled order - for an order still
+            // being incrementally filled/hedged, this folds the chunk in and
+            // leaves `BotState` reporting the order's current fill state
+            state.finish_hedge(executable.size, hedge_avg_price, realized_spread_bps, opened_at_ms, hedged_at_ms)
+        };
+
+        info!(
+            "[HEDGE] {} fully hedged @ {:.4} (realized {:.2} bps)",
+            executable.fill_id, hedge_avg_price, realized_spread_bps
+        );
+
+        if let Some(record) = record {
+            // No `OpportunityRecorder` sink is wired into `HedgeService` yet,
+            // so surface the closed cycle via the log for now rather than
+            // silently dropping it now that `mark_complete` actually fires
+            info!(
+                "[HEDGE] {} closed cycle: {:?} {} @ pacifica {:.4} / hyperliquid {:.4} (expected {:.2} bps, realized {:.2} bps)",
+                executable.fill_id,
+                record.direction,
+                record.size,
+                record.pacifica_price,
+                record.hyperliquid_price,
+                record.initial_profit_bps,
+                record.realized_profit_bps,
+            );
+        }
+    }
+
+    /// `RollbackMode::WidenedCross` resolution: slippage retries are
+    /// exhausted, so make one last attempt at a deeply widened, effectively
+    /// marketable-cross price instead of retrying indefinitely - then, if
+    /// even that comes up short or errors, fall back to unwinding on
+    /// Pacifica, the same terminal outcome `RollbackMode::FlattenOnPacifica`
+    /// would have taken immediately
+    #[allow(clippy::too_many_arguments)]
+    async fn rollback_widened_cross(
+        &self,
+        fill_id: &str,
+        executable: &mut ExecutableMatch,
+        hedge_side: OrderSide,
+        maker_side: OrderSide,
+        maker_avg_price: f64,
+        residual_size: f64,
+        fill_source: FillSource,
+        fill_detect_timestamp: Instant,
+    ) {
+        let slippage = self.config.hedge_rollback_cross_slippage;
+        warn!(
+            "[HEDGE] {} slippage retries exhausted - attempting final widened cross ({:.3}%) for residual {}",
+            fill_id,
+            slippage * 100.0,
+            residual_size
+        );
+
+        let hedge_started = Instant::now();
+        let hedge_result = self
+            .hyperliquid_trading
+            .place_market_order(&self.symbol, hedge_side, residual_size, slippage)
+            .await;
+
+        match hedge_result {
+            Ok((filled_size, hedge_avg_price)) if filled_size >= residual_size - f64::EPSILON => {
+                self.metrics.hedge_fill_latency.observe(hedge_started.elapsed());
+                self.metrics.fill_latency.record_dispatch_to_ack(fill_source, hedge_started.elapsed());
+                executable.record_hedge_fill(filled_size, hedge_avg_price);
+                self.finish_hedged(executable, maker_side, maker_avg_price, fill_source, fill_detect_timestamp)
+                    .await;
+            }
+            Ok((filled_size, hedge_avg_price)) => {
+                self.metrics.hedge_fill_latency.observe(hedge_started.elapsed());
+                self.metrics.fill_latency.record_dispatch_to_ack(fill_source, hedge_started.elapsed());
+                let still_unhedged = executable.record_hedge_fill(filled_size, hedge_avg_price);
+                executable.fail_rollback("widened cross filled short");
+                self.unwind(fill_id, maker_side, maker_avg_price, still_unhedged).await;
+            }
+            Err(e) => {
+                executable.fail_rollback(e.to_string());
+                self.unwind(fill_id, maker_side, maker_avg_price, residual_size).await;
+            }
+        }
+    }
+
+    /// Hedge retries are exhausted - flatten the naked Pacifica leg with a
+    /// market order, retrying the flatten itself on failure, and surface
+    /// loudly if it can't be completed at all
+    async fn unwind(&self, fill_id: &str, maker_side: OrderSide, maker_avg_price: f64, residual_size: f64) {
+        self.bot_state.write().await.begin_unwind();
+        self.metrics.record_hedge_result(false);
+        warn!("[HEDGE] {} hedge retries exhausted - unwinding {} on Pacifica", fill_id, residual_size);
+
+        let unwind_side = match maker_side {
+            OrderSide::Buy => PacificaOrderSide::Sell,
+            OrderSide::Sell => PacificaOrderSide::Buy,
+        };
+
+        let mut remaining = residual_size;
+        let mut attempt = 0u32;
+        // Tracks the size-weighted average fill price across every retry
+        // attempt, not just the last one - a partially-filled-then-retried
+        // unwind can clear at a meaningfully different price per attempt, and
+        // pricing the whole quantity off only the final attempt's average
+        // misstates the realized loss
+        let mut total_filled = 0.0f64;
+        let mut notional_sum = 0.0f64;
+        loop {
+            attempt += 1;
+            match self
+                .pacifica_trading
+                .place_market_order(&self.symbol, unwind_side, remaining)
+                .await
+            {
+                Ok((filled_size, unwind_avg_price)) => {
+                    total_filled += filled_size;
+                    notional_sum += filled_size * unwind_avg_price;
+                    remaining = (remaining - filled_size).max(0.0);
+                    if remaining <= 0.0 {
+                        let weighted_avg_price = if total_filled > 0.0 { notional_sum / total_filled } else { unwind_avg_price };
+                        let realized_loss = match maker_side {
+                            OrderSide::Buy => (maker_avg_price - weighted_avg_price) * total_filled,
+                            OrderSide::Sell => (weighted_avg_price - maker_avg_price) * total_filled,
+                        };
+                        self.bot_state.write().await.finish_unwind(realized_loss);
+                        let unwind_side_generic = match maker_side {
+                            OrderSide::Buy => OrderSide::Sell,
+                            OrderSide::Sell => OrderSide::Buy,
+                        };
+                        self.hedge_feed
+                            .record_unwind_settled(&self.symbol, unwind_side_generic, total_filled, weighted_avg_price);
+                        error!(
+                            "[HEDGE] {} unwound naked position on Pacifica @ {:.4} (realized loss {:.4}) after exhausting hedge retries",
+                            fill_id, weighted_avg_price, realized_loss
+                        );
+                        return;
+                    }
+                }
+                Err(e) => {
+                    error!("[HEDGE] {} unwind attempt #{} failed: {} - retrying", fill_id, attempt, e);
+                }
+            }
+
+            if attempt >= self.config.max_hedge_retries {
+                self.bot_state.write().await.set_error(format!(
+                    "failed to unwind naked {} position for fill {} after {} attempts",
+                    residual_size, fill_id, attempt
+                ));
+                error!(
+                    "[HEDGE] {} CRITICAL: unable to unwind naked position after {} attempts - manual intervention required",
+                    fill_id, attempt
+                );
+                return;
+            }
+
+            tokio::time::sleep(Duration::from_millis(200 * attempt as u64)).await;
+        }
+    }
+}
@@ -1,22 +1,30 @@
 /// Service modules - each task runs in its own service
 
-pub mod fill_detection;
+pub mod connectivity;
+pub mod fill_ledger;
 pub mod hedge;
+pub mod hedge_feed;
 pub mod orderbook;
 pub mod order_monitor;
-pub mod position_monitor;
-pub mod rest_fill_detection;
-pub mod rest_poll;
 pub mod order_placement;
+pub mod postgres_storage;
+pub mod state_feed;
 
-pub use fill_detection::FillDetectionService;
-pub use hedge::HedgeService;
-pub use orderbook::{PacificaOrderbookService, HyperliquidOrderbookService};
+pub use connectivity::{ConnectivitySupervisor, DetectionMode};
+// `fill_ledger::FillEvent`/`FillSource` are addressed via their module path
+// (`crate::services::fill_ledger::FillEvent`) rather than re-exported here,
+// since `hedge::FillEvent` already holds that name at this level.
+pub use fill_ledger::FillLedger;
+pub use hedge::{FillEvent, HedgeService};
+pub use hedge_feed::{HedgeFeedPublisher, HedgeFeedServer, HedgeReferenceState};
+pub use orderbook::{
+    check_hedge_epoch_freshness, check_price_freshness, HyperliquidOrderbookService, PacificaOrderbookService,
+    PriceFeed, PriceSource, TimestampedPrice,
+};
 pub use order_monitor::OrderMonitorService;
-pub use position_monitor::PositionMonitorService;
-pub use rest_fill_detection::RestFillDetectionService;
-pub use rest_poll::{PacificaRestPollService, HyperliquidRestPollService};
 pub use order_placement::{OrderPlacementService, OrderPlacementRequest};
+pub use postgres_storage::{HedgeRecord, PostgresStorageConfig, PostgresSslMode, PostgresStorageService};
+pub use state_feed::{StateFeedServer, StateMessage, StatePublisher, StateSnapshot};
 
 use crate::strategy::OrderSide;
 
@@ -1,15 +1,25 @@
-use std::sync::atomic::{AtomicU8, Ordering};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use dashmap::DashMap;
+use hdrhistogram::Histogram;
 use parking_lot::Mutex;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, Notify, RwLock, Semaphore};
 use tokio::time::interval;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::bot::{BotState, BotStatus};
 use crate::config::Config;
 use crate::connector::hyperliquid::HyperliquidTrading;
 use crate::connector::pacifica::PacificaTrading;
+use crate::services::connectivity::DetectionMode;
+// `fill_ledger::FillEvent` is aliased since `hedge::FillEvent` already holds
+// that name at this level (see `services::mod`'s re-export comment)
+use crate::services::fill_ledger::{FillAccumulator, FillEvent as LedgerFillEvent, FillLedger, FillSource};
+use crate::services::hedge::FillEvent;
+use crate::services::orderbook::TimestampedPrice;
+use crate::services::state_feed::{StatePublisher, StateSnapshot};
 use crate::strategy::{OpportunityEvaluator, OrderSide};
 use crate::util::rate_limit::{is_rate_limit_error, RateLimitTracker};
 
@@ -33,9 +43,15 @@ impl From<&BotStatus> for AtomicBotStatus {
         match status {
             BotStatus::Idle => AtomicBotStatus::Idle,
             BotStatus::OrderPlaced => AtomicBotStatus::OrderPlaced,
+            // Treated like OrderPlaced: the resting order is still live and subject
+            // to the same age/profit-deviation monitoring as an unfilled order
+            BotStatus::PartiallyFilled => AtomicBotStatus::OrderPlaced,
             BotStatus::Filled => AtomicBotStatus::Filled,
             BotStatus::Hedging => AtomicBotStatus::Hedging,
             BotStatus::Complete => AtomicBotStatus::Complete,
+            // Not a real Idle state, but the hot-path monitor has nothing to
+            // do while a position is being unwound - it isn't an active order
+            BotStatus::Unwinding => AtomicBotStatus::Idle,
             BotStatus::Error(_) => AtomicBotStatus::Idle, // Treat errors as idle for monitoring purposes
         }
     }
@@ -79,6 +95,32 @@ impl SharedOrderSnapshot {
     }
 }
 
+// ============================================================================
+// PER-SYMBOL STATE (MULTI-SYMBOL MONITORING)
+// ============================================================================
+
+/// Everything the monitor/cancellation/hedge paths need that's specific to one
+/// symbol, keyed into `OrderMonitorService::symbols`. Letting one service
+/// instance iterate a set of these in a single 1kHz pass - rather than running
+/// a fully duplicated `OrderMonitorService` per symbol - means the hot loop,
+/// rate-limit accounting, and cancellation DLQ are shared across every pair a
+/// bot trades instead of each spawning its own.
+///
+/// Every field is either an `Arc`, a `Sender`, or already `Clone`
+/// (`OpportunityEvaluator`), so cloning one out of the `DashMap` to hold
+/// across an `.await` is cheap and avoids pinning a shard lock for the
+/// duration of a REST round-trip.
+#[derive(Clone)]
+pub struct SymbolState {
+    pub bot_state: Arc<RwLock<BotState>>,
+    pub atomic_status: Arc<AtomicU8>,
+    pub order_snapshot: Arc<SharedOrderSnapshot>,
+    pub pacifica_prices: Arc<Mutex<TimestampedPrice>>,
+    pub hyperliquid_prices: Arc<Mutex<TimestampedPrice>>,
+    pub evaluator: OpportunityEvaluator,
+    pub hedge_tx: mpsc::Sender<FillEvent>,
+}
+
 // ============================================================================
 // CANCELLATION REQUEST CHANNEL (DECOUPLE FROM HOT PATH)
 // ============================================================================
@@ -87,9 +129,171 @@ impl SharedOrderSnapshot {
 #[derive(Debug)]
 pub enum CancelRequest {
     /// Cancel due to age expiry
-    AgeExpiry { symbol: String, reason: String },
+    AgeExpiry { symbol: String, reason: String, sent_at: Instant },
     /// Cancel due to profit deviation
-    ProfitDeviation { symbol: String, current_profit_bps: f64, deviation_bps: f64 },
+    ProfitDeviation { symbol: String, current_profit_bps: f64, deviation_bps: f64, sent_at: Instant },
+}
+
+impl CancelRequest {
+    fn sent_at(&self) -> Instant {
+        match self {
+            CancelRequest::AgeExpiry { sent_at, .. } => *sent_at,
+            CancelRequest::ProfitDeviation { sent_at, .. } => *sent_at,
+        }
+    }
+
+    fn symbol(&self) -> &str {
+        match self {
+            CancelRequest::AgeExpiry { symbol, .. } => symbol,
+            CancelRequest::ProfitDeviation { symbol, .. } => symbol,
+        }
+    }
+}
+
+// ============================================================================
+// CANCELLATION DEAD-LETTER QUEUE (RETRY ESCALATION)
+// ============================================================================
+
+/// One retry attempt sitting in the cancellation DLQ: the original request
+/// plus how many times cancellation has already been retried for it
+#[derive(Debug)]
+struct CancelDlqEntry {
+    request: CancelRequest,
+    attempt: u32,
+}
+
+/// Bounded, drop-oldest queue for cancels that failed for a reason other than
+/// rate-limiting (those are left to `RateLimitTracker`'s own backoff).
+///
+/// A plain `mpsc::channel`, like `cancel_tx` below, rejects the *newest* item
+/// once full - the wrong trade-off here, since a cancel that's already been
+/// retried a few times is more important to keep live than one that just
+/// failed for the first time. This drops the oldest queued retry instead to
+/// make room, logging a warning when it does.
+struct CancelDlq {
+    queue: Mutex<VecDeque<CancelDlqEntry>>,
+    notify: Notify,
+    capacity: usize,
+}
+
+impl CancelDlq {
+    fn new(capacity: usize) -> Self {
+        Self {
+            queue: Mutex::new(VecDeque::with_capacity(capacity)),
+            notify: Notify::new(),
+            capacity,
+        }
+    }
+
+    fn push(&self, entry: CancelDlqEntry) {
+        let mut queue = self.queue.lock();
+        if queue.len() >= self.capacity {
+            if let Some(dropped) = queue.pop_front() {
+                warn!(
+                    "[CANCEL_DLQ] saturated at {} entries - dropping oldest retry ({:?}, attempt #{})",
+                    self.capacity, dropped.request, dropped.attempt
+                );
+            }
+        }
+        queue.push_back(entry);
+        drop(queue);
+        self.notify.notify_one();
+    }
+
+    /// Wait for and pop the next entry. Registers the `Notified` future
+    /// before re-checking the queue, so a `push` landing between the empty
+    /// check and the await can't be missed.
+    async fn pop(&self) -> CancelDlqEntry {
+        loop {
+            let notified = self.notify.notified();
+            if let Some(entry) = self.queue.lock().pop_front() {
+                return entry;
+            }
+            notified.await;
+        }
+    }
+}
+
+// ============================================================================
+// HOT-PATH LATENCY PROFILING
+// ============================================================================
+
+/// Microsecond-resolution percentile tracking for the 1kHz monitor loop and
+/// the cancel round-trip.
+///
+/// `crate::metrics::LatencyHistogram` buckets at 1ms resolution, which is too
+/// coarse to tell whether this loop is actually meeting the sub-millisecond
+/// budget documented on `run_monitor_loop` - this tracks exact percentiles in
+/// microseconds instead, recorded behind a `parking_lot::Mutex` the same way
+/// the price feeds are.
+pub struct HotPathProfiler {
+    budget: Duration,
+    iteration_micros: Mutex<Histogram<u64>>,
+    cancel_roundtrip_micros: Mutex<Histogram<u64>>,
+    iterations_over_budget: AtomicU64,
+}
+
+impl HotPathProfiler {
+    fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            // 3 significant digits is ample resolution for sub-second latencies
+            iteration_micros: Mutex::new(Histogram::new(3).expect("valid histogram precision")),
+            cancel_roundtrip_micros: Mutex::new(Histogram::new(3).expect("valid histogram precision")),
+            iterations_over_budget: AtomicU64::new(0),
+        }
+    }
+
+    /// Record one monitor-loop iteration's wall time (snapshot get + price
+    /// lock + profit recompute)
+    fn record_iteration(&self, elapsed: Duration) {
+        if elapsed > self.budget {
+            self.iterations_over_budget.fetch_add(1, Ordering::Relaxed);
+        }
+        let micros = elapsed.as_micros().min(u64::MAX as u128) as u64;
+        let _ = self.iteration_micros.lock().record(micros);
+    }
+
+    /// Record the time from `try_send(CancelRequest)` to the cancellation
+    /// handler finishing the REST cancel
+    fn record_cancel_roundtrip(&self, elapsed: Duration) {
+        let micros = elapsed.as_micros().min(u64::MAX as u128) as u64;
+        let _ = self.cancel_roundtrip_micros.lock().record(micros);
+    }
+
+    /// p50/p90/p99/max (in microseconds) for both histograms, plus the count
+    /// of iterations that overran `budget`, for periodic logging
+    fn summary(&self) -> HotPathSummary {
+        let iteration = self.iteration_micros.lock();
+        let cancel = self.cancel_roundtrip_micros.lock();
+        HotPathSummary {
+            iteration_count: iteration.len(),
+            iteration_p50_us: iteration.value_at_quantile(0.50),
+            iteration_p90_us: iteration.value_at_quantile(0.90),
+            iteration_p99_us: iteration.value_at_quantile(0.99),
+            iteration_max_us: iteration.max(),
+            iterations_over_budget: self.iterations_over_budget.load(Ordering::Relaxed),
+            cancel_count: cancel.len(),
+            cancel_p50_us: cancel.value_at_quantile(0.50),
+            cancel_p90_us: cancel.value_at_quantile(0.90),
+            cancel_p99_us: cancel.value_at_quantile(0.99),
+            cancel_max_us: cancel.max(),
+        }
+    }
+}
+
+struct HotPathSummary {
+    iteration_count: u64,
+    iteration_p50_us: u64,
+    iteration_p90_us: u64,
+    iteration_p99_us: u64,
+    iteration_max_us: u64,
+    iterations_over_budget: u64,
+    cancel_count: u64,
+    cancel_p50_us: u64,
+    cancel_p90_us: u64,
+    cancel_p99_us: u64,
+    cancel_max_us: u64,
 }
 
 // ============================================================================
@@ -98,81 +302,154 @@ pub enum CancelRequest {
 
 /// Order monitoring service
 ///
-/// Monitors active orders for:
+/// Monitors every active symbol's order for:
 /// 1. Age - refreshes order if age > order_refresh_interval_secs
 /// 2. Profit deviation - cancels if profit drops > profit_cancel_threshold_bps
 /// 3. Periodic profit logging every 2 seconds
 ///
 /// Key optimizations:
-/// - Lock-free status check via atomic
+/// - Lock-free status check via atomic, per symbol
 /// - No REST calls in hot loop (delegated to separate task)
 /// - No cloning (uses lightweight snapshot)
 /// - No allocations in hot path
+///
+/// Runs one 1kHz loop over every registered symbol instead of requiring a
+/// fully duplicated service per symbol: `symbols` holds each pair's
+/// `SymbolState`, `register_symbol` adds one when a `Supervisor` brings up a
+/// new pair, and cancellation/rate-limit accounting stays shared across all
+/// of them via `cancel_tx`/`cancel_dlq`.
 pub struct OrderMonitorService {
-    // Shared state (write lock only needed for mutations)
-    pub bot_state: Arc<RwLock<BotState>>,
-    
-    // Lock-free status for hot path (updated by state manager)
-    pub atomic_status: Arc<AtomicU8>,
-    
-    // Lightweight order snapshot (updated when order placed)
-    pub order_snapshot: Arc<SharedOrderSnapshot>,
-    
-    // Price feeds (lock-free reads via parking_lot)
-    pub pacifica_prices: Arc<Mutex<(f64, f64)>>,
-    pub hyperliquid_prices: Arc<Mutex<(f64, f64)>>,
-    
-    // Configuration
+    symbols: DashMap<String, SymbolState>,
+
+    // Configuration (knobs shared across every registered symbol - refresh
+    // interval, profit thresholds, rate limits, etc.)
     pub config: Config,
-    pub evaluator: OpportunityEvaluator,
-    
+
     // Trading connectors (only used by cancellation task)
     pub pacifica_trading: Arc<PacificaTrading>,
     pub hyperliquid_trading: Arc<HyperliquidTrading>,
-    
+
     // Channel for cancel requests (decouples hot path from I/O)
     pub cancel_tx: mpsc::Sender<CancelRequest>,
+
+    // Cancels that failed for a reason other than rate-limiting land here for
+    // `run_cancel_dlq_handler` to retry with backoff, independently of
+    // `run_cancellation_handler`
+    pub cancel_dlq: Arc<CancelDlq>,
+
+    // Microsecond-resolution latency tracking for the hot path and the
+    // cancel round-trip
+    pub hot_path_profiler: Arc<HotPathProfiler>,
+
+    // Fans state transitions out to connected state-feed dashboards
+    pub state_publisher: Arc<StatePublisher>,
+
+    // Which fill-detection layer `ConnectivitySupervisor` currently considers
+    // authoritative; `None` if no supervisor is wired up, in which case the
+    // fill poller always runs at the slow heartbeat cadence
+    pub detection_mode: Option<Arc<AtomicU8>>,
+
+    // Shared dedup point for the REST fill poller (and any future
+    // fill-detection layer, e.g. WebSocket): gates each observed cumulative
+    // fill report so a duplicate or stale poll never reaches
+    // `BotState::mark_filled` as a new delta
+    pub fill_ledger: Arc<FillLedger>,
+
+    // Batches partial fills into sized hedge chunks instead of forwarding
+    // every individual delta, keyed by client_order_id
+    pub fill_accumulator: Arc<FillAccumulator>,
+
+    // Fans fill/hedge/unwind events and the running net-position reference
+    // state out to connected hedge-feed dashboards
+    pub hedge_feed: Arc<crate::services::hedge_feed::HedgeFeedPublisher>,
 }
 
 impl OrderMonitorService {
-    /// Create a new order monitor service with cancellation channel
+    /// Create a new, symbol-less order monitor service with its cancellation
+    /// channel. Call `register_symbol` for each pair this instance should
+    /// monitor before spawning its tasks. `detection_mode` should be
+    /// `ConnectivitySupervisor::mode_handle()`'s return value, if a
+    /// supervisor is running - the fill poller escalates to aggressive
+    /// polling while it reads `DetectionMode::Rest`.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        bot_state: Arc<RwLock<BotState>>,
-        atomic_status: Arc<AtomicU8>,
-        order_snapshot: Arc<SharedOrderSnapshot>,
-        pacifica_prices: Arc<Mutex<(f64, f64)>>,
-        hyperliquid_prices: Arc<Mutex<(f64, f64)>>,
         config: Config,
-        evaluator: OpportunityEvaluator,
         pacifica_trading: Arc<PacificaTrading>,
         hyperliquid_trading: Arc<HyperliquidTrading>,
+        state_publisher: Arc<StatePublisher>,
+        hedge_feed: Arc<crate::services::hedge_feed::HedgeFeedPublisher>,
+        detection_mode: Option<Arc<AtomicU8>>,
     ) -> (Self, mpsc::Receiver<CancelRequest>) {
         // Bounded channel to prevent unbounded growth, but large enough to not block
         let (cancel_tx, cancel_rx) = mpsc::channel(64);
-        
+        let cancel_dlq = Arc::new(CancelDlq::new(config.cancel_dlq_capacity));
+        let fill_ledger = Arc::new(FillLedger::new());
+        let fill_accumulator = Arc::new(FillAccumulator::new(
+            config.hedge_chunk_notional_usd,
+            Duration::from_millis(config.hedge_chunk_max_latency_ms),
+        ));
+
         let service = Self {
-            bot_state,
-            atomic_status,
-            order_snapshot,
-            pacifica_prices,
-            hyperliquid_prices,
+            symbols: DashMap::new(),
             config,
-            evaluator,
             pacifica_trading,
             hyperliquid_trading,
             cancel_tx,
+            cancel_dlq,
+            hot_path_profiler: Arc::new(HotPathProfiler::new(Duration::from_millis(1))),
+            state_publisher,
+            detection_mode,
+            fill_ledger,
+            fill_accumulator,
+            hedge_feed,
         };
-        
+
         (service, cancel_rx)
     }
 
+    /// Register a symbol's state so the monitor/cancellation/hedge paths
+    /// start tracking it. Replaces any existing entry for the same symbol.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_symbol(
+        &self,
+        symbol: String,
+        bot_state: Arc<RwLock<BotState>>,
+        atomic_status: Arc<AtomicU8>,
+        order_snapshot: Arc<SharedOrderSnapshot>,
+        pacifica_prices: Arc<Mutex<TimestampedPrice>>,
+        hyperliquid_prices: Arc<Mutex<TimestampedPrice>>,
+        evaluator: OpportunityEvaluator,
+        hedge_tx: mpsc::Sender<FillEvent>,
+    ) {
+        self.symbols.insert(
+            symbol,
+            SymbolState {
+                bot_state,
+                atomic_status,
+                order_snapshot,
+                pacifica_prices,
+                hyperliquid_prices,
+                evaluator,
+                hedge_tx,
+            },
+        );
+    }
+
+    /// Clone a symbol's state out of the map, if it's still registered.
+    /// Cloning is cheap (see `SymbolState`) and lets callers hold it across
+    /// `.await` points without pinning a `DashMap` shard lock.
+    fn symbol_state(&self, symbol: &str) -> Option<SymbolState> {
+        self.symbols.get(symbol).map(|entry| entry.clone())
+    }
+
     /// Main monitoring loop - LATENCY CRITICAL
-    /// 
-    /// This loop runs at 1kHz and must complete each iteration in <1ms.
-    /// All I/O operations are delegated to separate tasks via channels.
+    ///
+    /// This loop runs at 1kHz and must complete each iteration in <1ms, across
+    /// every registered symbol, not just one. All I/O operations are
+    /// delegated to separate tasks via channels.
     pub async fn run_monitor_loop(&self) {
         let mut monitor_interval = interval(Duration::from_millis(1));
-        
+
         // Timing thresholds
         let age_threshold = Duration::from_secs(self.config.order_refresh_interval_secs);
         let profit_threshold = self.config.profit_cancel_threshold_bps;
@@ -180,214 +457,499 @@ impl OrderMonitorService {
         loop {
             monitor_interval.tick().await;
 
-            // FAST PATH: Lock-free status check
-            let status = self.atomic_status.load(Ordering::Acquire);
-            if status != AtomicBotStatus::OrderPlaced as u8 {
-                continue;
-            }
+            let iteration_started = Instant::now();
+            for entry in self.symbols.iter() {
+                let symbol = entry.key();
+                let state = entry.value();
 
-            // Get order snapshot (single lock, no clone of complex types)
-            let snapshot = match self.order_snapshot.get() {
-                Some(s) => s,
-                None => continue,
-            };
+                // FAST PATH: Lock-free status check
+                if state.atomic_status.load(Ordering::Acquire) != AtomicBotStatus::OrderPlaced as u8 {
+                    continue;
+                }
 
-            // Get prices (parking_lot mutex is very fast for uncontended case)
-            let (hl_bid, hl_ask) = *self.hyperliquid_prices.lock();
-            if hl_bid == 0.0 || hl_ask == 0.0 {
-                continue;
+                self.monitor_tick(symbol, state, age_threshold, profit_threshold);
             }
+            self.hot_path_profiler.record_iteration(iteration_started.elapsed());
+        }
+    }
+
+    /// The actual per-symbol, per-iteration hot-path work: snapshot get +
+    /// price lock + profit recompute, timed as a unit (across all symbols) by
+    /// `run_monitor_loop` via `hot_path_profiler`
+    fn monitor_tick(&self, symbol: &str, state: &SymbolState, age_threshold: Duration, profit_threshold: f64) {
+        // Get order snapshot (single lock, no clone of complex types)
+        let snapshot = match state.order_snapshot.get() {
+            Some(s) => s,
+            None => return,
+        };
 
-            let age = snapshot.placed_at.elapsed();
+        // Get prices (parking_lot mutex is very fast for uncontended case).
+        // Pull bid/ask out under the lock rather than cloning the whole quote
+        // (which now also carries depth levels) - this loop is latency
+        // critical and has no use for depth, just the top of book.
+        let (hl_bid, hl_ask) = {
+            let guard = state.hyperliquid_prices.lock();
+            (guard.bid, guard.ask)
+        };
+        if hl_bid == 0.0 || hl_ask == 0.0 {
+            return;
+        }
 
-            // Check 1: Age threshold
-            if age > age_threshold {
-                // Send cancel request (non-blocking)
-                let _ = self.cancel_tx.try_send(CancelRequest::AgeExpiry {
-                    symbol: self.config.symbol.clone(),
-                    reason: format!("age {}ms > {}s threshold", age.as_millis(), self.config.order_refresh_interval_secs),
-                });
-                continue;
-            }
+        let age = snapshot.placed_at.elapsed();
 
-            // Check 2: Profit deviation (using raw method - no allocation)
-            let current_profit = self.evaluator.recalculate_profit_raw(
-                snapshot.side,
-                snapshot.price,
-                hl_bid,
-                hl_ask,
-            );
-            
-            // Consistent calculation: positive = profit dropped (bad)
-            let profit_change = snapshot.initial_profit_bps - current_profit;
-            let profit_deviation = profit_change.abs();
-
-            if profit_deviation > profit_threshold {
-                // Send cancel request (non-blocking)
-                let _ = self.cancel_tx.try_send(CancelRequest::ProfitDeviation {
-                    symbol: self.config.symbol.clone(),
-                    current_profit_bps: current_profit,
-                    deviation_bps: profit_deviation,
-                });
-            }
+        // Check 1: Age threshold
+        if age > age_threshold {
+            // Send cancel request (non-blocking)
+            let _ = self.cancel_tx.try_send(CancelRequest::AgeExpiry {
+                symbol: symbol.to_string(),
+                reason: format!("age {}ms > {}s threshold", age.as_millis(), self.config.order_refresh_interval_secs),
+                sent_at: Instant::now(),
+            });
+            return;
+        }
+
+        // Check 2: Profit deviation (using raw method - no allocation)
+        let current_profit = state.evaluator.recalculate_profit_raw(
+            snapshot.side,
+            snapshot.price,
+            hl_bid,
+            hl_ask,
+        );
+
+        // Consistent calculation: positive = profit dropped (bad)
+        let profit_change = snapshot.initial_profit_bps - current_profit;
+        let profit_deviation = profit_change.abs();
+
+        if profit_deviation > profit_threshold {
+            // Send cancel request (non-blocking)
+            let _ = self.cancel_tx.try_send(CancelRequest::ProfitDeviation {
+                symbol: symbol.to_string(),
+                current_profit_bps: current_profit,
+                deviation_bps: profit_deviation,
+                sent_at: Instant::now(),
+            });
         }
     }
 
     /// Cancellation handler task - runs separately from hot path
     /// 
     /// Handles all I/O operations: REST API calls, state updates, logging
+    ///
+    /// Dispatches each request to its own task, bounded by
+    /// `config.max_concurrent_cancellations`, so one slow REST round-trip
+    /// (age expiry on one cycle, say) can't head-of-line-block a cancel
+    /// queued for an unrelated reason right behind it.
     pub async fn run_cancellation_handler(
-        &self,
+        self: Arc<Self>,
         mut cancel_rx: mpsc::Receiver<CancelRequest>,
     ) {
-        let mut rate_limit = RateLimitTracker::new();
+        let rate_limit = Arc::new(Mutex::new(RateLimitTracker::new()));
+        let semaphore = Arc::new(Semaphore::new(self.config.max_concurrent_cancellations));
 
         while let Some(request) = cancel_rx.recv().await {
             // Check rate limit backoff
-            if rate_limit.should_skip() {
+            if rate_limit.lock().should_skip() {
                 debug!(
                     "[CANCEL] Skipping cancellation (rate limit backoff, {:.1}s remaining)",
-                    rate_limit.remaining_backoff_secs()
+                    rate_limit.lock().remaining_backoff_secs()
                 );
                 continue;
             }
 
             // Double-check state hasn't changed (order might have filled)
-            let status = self.atomic_status.load(Ordering::Acquire);
+            let symbol = request.symbol();
+            let Some(state) = self.symbols.get(symbol) else {
+                debug!("[CANCEL] Skipping - unknown symbol {}", symbol);
+                continue;
+            };
+            let status = state.atomic_status.load(Ordering::Acquire);
+            drop(state);
             if status != AtomicBotStatus::OrderPlaced as u8 {
                 debug!("[CANCEL] Skipping - status changed to {}", status);
                 continue;
             }
 
-            // Get current snapshot for logging
-            let _snapshot = self.order_snapshot.get();
+            let Ok(permit) = Arc::clone(&semaphore).acquire_owned().await else {
+                // Semaphore only closes if it's dropped, which doesn't happen
+                // while this loop is still holding `Arc::clone` of it
+                break;
+            };
+            let service = Arc::clone(&self);
+            let rate_limit = Arc::clone(&rate_limit);
+            tokio::spawn(async move {
+                service.process_cancel_request(request, &rate_limit).await;
+                drop(permit);
+            });
+        }
+    }
+
+    /// Handle a single cancel request end to end: hedge away any observed
+    /// fill, cancel the resting order, then run the flat-position health
+    /// assertion before handing off to `finish_cancel`. Every REST call is
+    /// bounded by `config.cancel_rest_timeout_ms` and a timeout is treated
+    /// the same as any other retryable failure.
+    async fn process_cancel_request(&self, request: CancelRequest, rate_limit: &Mutex<RateLimitTracker>) {
+        let rest_timeout = Duration::from_millis(self.config.cancel_rest_timeout_ms);
+        let symbol = request.symbol().to_string();
+
+        let Some(state) = self.symbol_state(&symbol) else {
+            debug!("[CANCEL] Skipping - unknown symbol {}", symbol);
+            return;
+        };
+
+        // Check for partial fills before cancelling - any unhedged quantity is
+        // hedged now so cancelling the remainder never leaves it naked
+        match tokio::time::timeout(rest_timeout, self.check_for_fills(&state)).await {
+            Ok(FillCheckResult::HasFills(cumulative_filled)) => {
+                info!(
+                    "[CANCEL] Order has fills ({}) - hedging filled quantity before cancelling remainder",
+                    cumulative_filled
+                );
+                self.hedge_residual_before_cancel(&state, cumulative_filled).await;
+                // Fall through to cancel the remaining resting quantity
+            }
+            Ok(FillCheckResult::NotFound) => {
+                debug!("[CANCEL] Order not in open orders - might be filled/cancelled");
+                return;
+            }
+            Ok(FillCheckResult::NoFills) => {
+                // Safe to proceed with cancellation
+            }
+            Ok(FillCheckResult::CheckFailed(e)) => {
+                debug!("[CANCEL] Fill check failed: {} - proceeding with cancellation", e);
+                // Continue with cancellation (safer than leaving hanging orders)
+            }
+            Err(_) => {
+                debug!("[CANCEL] Fill check timed out after {}ms - proceeding with cancellation", self.config.cancel_rest_timeout_ms);
+            }
+        }
+
+        // Log the cancellation reason
+        match &request {
+            CancelRequest::AgeExpiry { reason, .. } => {
+                info!("[CANCEL] Age expiry: {}", reason);
+            }
+            CancelRequest::ProfitDeviation { current_profit_bps, deviation_bps, .. } => {
+                info!(
+                    "[CANCEL] Profit deviation: current={:.2} bps, deviation={:.2} bps",
+                    current_profit_bps, deviation_bps
+                );
+            }
+        }
+
+        let cancel_result = tokio::time::timeout(
+            rest_timeout,
+            self.pacifica_trading.cancel_all_orders(false, Some(&symbol), false),
+        )
+        .await;
 
-            // Check for partial fills before cancelling
-            match self.check_for_fills().await {
-                FillCheckResult::HasFills(amount) => {
-                    info!(
-                        "[CANCEL] Order has fills ({}) - skipping cancellation, waiting for fill detection",
-                        amount
+        match cancel_result {
+            Ok(Ok(_)) => {
+                rate_limit.lock().record_success();
+                self.hot_path_profiler.record_cancel_roundtrip(request.sent_at().elapsed());
+
+                let change = match &request {
+                    CancelRequest::AgeExpiry { .. } => "age expiry cancel",
+                    CancelRequest::ProfitDeviation { .. } => "profit deviation cancel",
+                };
+                self.finish_cancel(&state, change, request).await;
+
+                // Refresh prices in parallel (not blocking the handler)
+                let _ = tokio::time::timeout(rest_timeout, self.refresh_prices_parallel(&symbol, &state)).await;
+            }
+            Ok(Err(e)) => {
+                if is_rate_limit_error(&e) {
+                    let mut limiter = rate_limit.lock();
+                    limiter.record_error();
+                    warn!(
+                        "[CANCEL] Rate limit exceeded. Backing off for {}s (attempt #{})",
+                        limiter.get_backoff_secs(),
+                        limiter.consecutive_errors()
                     );
-                    continue;
-                }
-                FillCheckResult::NotFound => {
-                    debug!("[CANCEL] Order not in open orders - might be filled/cancelled");
-                    continue;
-                }
-                FillCheckResult::NoFills => {
-                    // Safe to proceed with cancellation
-                }
-                FillCheckResult::CheckFailed(e) => {
-                    debug!("[CANCEL] Fill check failed: {} - proceeding with cancellation", e);
-                    // Continue with cancellation (safer than leaving hanging orders)
+                } else {
+                    warn!("[CANCEL] Failed to cancel: {} - handing off to the cancellation DLQ", e);
+                    self.cancel_dlq.push(CancelDlqEntry { request, attempt: 1 });
                 }
             }
+            Err(_) => {
+                warn!(
+                    "[CANCEL] cancel_all_orders timed out after {}ms - handing off to the cancellation DLQ",
+                    self.config.cancel_rest_timeout_ms
+                );
+                self.cancel_dlq.push(CancelDlqEntry { request, attempt: 1 });
+            }
+        }
+    }
 
-            // Log the cancellation reason
-            match &request {
-                CancelRequest::AgeExpiry { reason, .. } => {
-                    info!("[CANCEL] Age expiry: {}", reason);
-                }
-                CancelRequest::ProfitDeviation { current_profit_bps, deviation_bps, .. } => {
-                    info!(
-                        "[CANCEL] Profit deviation: current={:.2} bps, deviation={:.2} bps",
-                        current_profit_bps, deviation_bps
-                    );
+    /// Confirm the bot is actually flat before it's safe to mark `Idle`:
+    /// no order still resting on Pacifica under `client_order_id`, and
+    /// nothing left over unhedged from a fill observed in the meantime.
+    ///
+    /// Exists because trusting a cancel response alone is optimistic - a
+    /// stale view, a race with a last-moment fill, or a degraded node
+    /// echoing back success could all leave a live order nobody is
+    /// watching if `BotState` were cleared regardless.
+    async fn assert_flat(&self, state: &SymbolState, client_order_id: &str) -> bool {
+        let rest_timeout = Duration::from_millis(self.config.cancel_rest_timeout_ms);
+        match tokio::time::timeout(rest_timeout, self.pacifica_trading.get_open_orders()).await {
+            Ok(Ok(orders)) => {
+                if orders.iter().any(|o| o.client_order_id == client_order_id) {
+                    warn!("[HEALTH] {} still resting on Pacifica after cancel - not safe to clear state", client_order_id);
+                    return false;
                 }
             }
+            Ok(Err(e)) => {
+                warn!("[HEALTH] failed to confirm {} is no longer resting: {}", client_order_id, e);
+                return false;
+            }
+            Err(_) => {
+                warn!(
+                    "[HEALTH] timed out confirming {} is no longer resting after {}ms",
+                    client_order_id, self.config.cancel_rest_timeout_ms
+                );
+                return false;
+            }
+        }
+
+        let unhedged = state.bot_state.read().await.unhedged_filled_size();
+        if unhedged > 0.0 {
+            warn!("[HEALTH] {} has {} unhedged after cancel - not safe to clear state", client_order_id, unhedged);
+            return false;
+        }
+
+        true
+    }
+
+    /// Clear the active order, sync the atomic, and publish `change` to the
+    /// state feed - the shared tail of every cancellation outcome: a clean
+    /// cancel here, a DLQ retry landing late, or a forced flatten-and-reset.
+    /// A no-op if the order has already moved past a cancellable status.
+    ///
+    /// Runs the flat-position health assertion first: if Pacifica disagrees
+    /// that the bot is flat, `original_request` is routed back through the
+    /// cancel DLQ instead of optimistically clearing state.
+    ///
+    /// `PartiallyFilled` is included alongside `OrderPlaced` since the caller
+    /// is expected to have already forwarded any unhedged quantity for
+    /// hedging (via `hedge_residual_before_cancel`) before reaching this.
+    async fn finish_cancel(&self, symbol_state: &SymbolState, change: &'static str, original_request: CancelRequest) {
+        let client_order_id = {
+            let state = symbol_state.bot_state.read().await;
+            if !matches!(state.status, BotStatus::OrderPlaced | BotStatus::PartiallyFilled) {
+                return;
+            }
+            match state.active_order.as_ref() {
+                Some(order) => order.client_order_id.clone(),
+                None => return,
+            }
+        };
+
+        if !self.assert_flat(symbol_state, &client_order_id).await {
+            warn!(
+                "[HEALTH] flat-position assertion failed for {} - routing back through the cancel DLQ instead of clearing state",
+                client_order_id
+            );
+            self.cancel_dlq.push(CancelDlqEntry { request: original_request, attempt: 1 });
+            return;
+        }
+
+        let mut state = symbol_state.bot_state.write().await;
+        if !matches!(state.status, BotStatus::OrderPlaced | BotStatus::PartiallyFilled) {
+            return;
+        }
+
+        state.clear_active_order();
+        let snapshot = StateSnapshot::capture(
+            &state,
+            &symbol_state.pacifica_prices.lock(),
+            &symbol_state.hyperliquid_prices.lock(),
+            &symbol_state.evaluator,
+        );
+        // Publisher is hooked here rather than through `sync_atomic_status`
+        // below so the broadcast message carries the cancellation reason
+        // instead of a generic "status -> Idle" transition
+        self.state_publisher.publish(snapshot.into_message(change));
+        sync_atomic_status(&symbol_state.atomic_status, &state.status, None);
+        drop(state);
+        symbol_state.order_snapshot.set(None);
+        // Drop the ledger entry now that this client_order_id is done, so a
+        // future (vanishingly unlikely) cloid collision can't inherit a stale max
+        self.fill_ledger.forget(&client_order_id);
+    }
+
+    /// Dedicated retry task for the cancellation DLQ - pulls a request, waits
+    /// an exponential backoff keyed to its attempt count, and re-attempts the
+    /// cancel. Runs independently of `run_cancellation_handler` so a backlog
+    /// of stuck retries never blocks newly observed age/profit-deviation
+    /// cancels.
+    pub async fn run_cancel_dlq_handler(&self) {
+        let rest_timeout = Duration::from_millis(self.config.cancel_rest_timeout_ms);
+
+        loop {
+            let entry = self.cancel_dlq.pop().await;
+            let backoff_exponent = entry.attempt.saturating_sub(1).min(8);
+            tokio::time::sleep(Duration::from_millis(200 * 2u64.pow(backoff_exponent))).await;
+
+            let symbol = entry.request.symbol().to_string();
 
-            // Execute cancellation
-            let symbol = match &request {
-                CancelRequest::AgeExpiry { symbol, .. } => symbol,
-                CancelRequest::ProfitDeviation { symbol, .. } => symbol,
+            let Some(state) = self.symbol_state(&symbol) else {
+                warn!("[CANCEL_DLQ] dropping retry for unknown symbol {}", symbol);
+                continue;
             };
 
-            match self.pacifica_trading.cancel_all_orders(false, Some(symbol), false).await {
-                Ok(_) => {
-                    rate_limit.record_success();
-                    
-                    // Clear state only if still in OrderPlaced
-                    let mut state = self.bot_state.write().await;
-                    if matches!(state.status, BotStatus::OrderPlaced) {
-                        state.clear_active_order();
-                        self.atomic_status.store(AtomicBotStatus::Idle as u8, Ordering::Release);
-                        self.order_snapshot.set(None);
-                    }
-                    drop(state);
+            let cancel_result =
+                tokio::time::timeout(rest_timeout, self.pacifica_trading.cancel_all_orders(false, Some(&symbol), false)).await;
 
-                    // Refresh prices in parallel (not blocking the handler)
-                    self.refresh_prices_parallel().await;
-                }
-                Err(e) => {
-                    if is_rate_limit_error(&e) {
-                        rate_limit.record_error();
-                        warn!(
-                            "[CANCEL] Rate limit exceeded. Backing off for {}s (attempt #{})",
-                            rate_limit.get_backoff_secs(),
-                            rate_limit.consecutive_errors()
-                        );
-                    } else {
-                        warn!("[CANCEL] Failed to cancel: {}", e);
-                    }
+            match cancel_result {
+                Ok(Ok(_)) => {
+                    info!("[CANCEL_DLQ] cancel succeeded on retry attempt #{}", entry.attempt);
+                    self.finish_cancel(&state, "cancel dlq retry succeeded", entry.request).await;
+                    continue;
                 }
+                Ok(Err(e)) => warn!("[CANCEL_DLQ] attempt #{} failed: {}", entry.attempt, e),
+                Err(_) => warn!(
+                    "[CANCEL_DLQ] attempt #{} timed out after {}ms",
+                    entry.attempt, self.config.cancel_rest_timeout_ms
+                ),
+            }
+
+            if entry.attempt >= self.config.max_cancel_retries {
+                self.escalate_cancel_exhausted(&state, entry.request, entry.attempt).await;
+            } else {
+                self.cancel_dlq.push(CancelDlqEntry { request: entry.request, attempt: entry.attempt + 1 });
             }
         }
     }
 
-    /// Periodic profit logging task - runs at 0.5 Hz (every 2 seconds)
+    /// DLQ retries are exhausted for this cancel request - force a flatten
+    /// and reset so the bot doesn't get stuck in `OrderPlaced` with a live,
+    /// unmanaged order: one last best-effort cancel-all, hedge away any fill
+    /// detected in the process, then clear state regardless of whether that
+    /// last cancel-all actually landed.
+    async fn escalate_cancel_exhausted(&self, state: &SymbolState, request: CancelRequest, attempts: u32) {
+        error!(
+            "[CANCEL_DLQ] CRITICAL: unable to cancel {:?} after {} attempts - forcing flatten-and-reset",
+            request, attempts
+        );
+
+        let rest_timeout = Duration::from_millis(self.config.cancel_rest_timeout_ms);
+        let _ = tokio::time::timeout(
+            rest_timeout,
+            self.pacifica_trading.cancel_all_orders(false, Some(request.symbol()), false),
+        )
+        .await;
+
+        if let Ok(FillCheckResult::HasFills(cumulative_filled)) = tokio::time::timeout(rest_timeout, self.check_for_fills(state)).await {
+            warn!("[CANCEL_DLQ] {} filled while stuck - hedging before reset", cumulative_filled);
+            self.hedge_residual_before_cancel(state, cumulative_filled).await;
+        }
+
+        self.finish_cancel(state, "cancel dlq exhausted - forced reset", request).await;
+    }
+
+    /// Periodic profit logging task - runs at 0.5 Hz (every 2 seconds), across
+    /// every registered symbol with an active order
     pub async fn run_profit_logger(&self) {
         let mut log_interval = interval(Duration::from_secs(2));
 
         loop {
             log_interval.tick().await;
 
-            // Only log for active orders
-            let status = self.atomic_status.load(Ordering::Acquire);
-            if status != AtomicBotStatus::OrderPlaced as u8 {
-                continue;
-            }
+            for entry in self.symbols.iter() {
+                let symbol = entry.key();
+                let state = entry.value();
 
-            let snapshot = match self.order_snapshot.get() {
-                Some(s) => s,
-                None => continue,
-            };
+                // Only log for active orders
+                if state.atomic_status.load(Ordering::Acquire) != AtomicBotStatus::OrderPlaced as u8 {
+                    continue;
+                }
 
-            let (hl_bid, hl_ask) = *self.hyperliquid_prices.lock();
-            if hl_bid == 0.0 || hl_ask == 0.0 {
-                continue;
+                let snapshot = match state.order_snapshot.get() {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                let hl_quote = state.hyperliquid_prices.lock().clone();
+                if hl_quote.bid == 0.0 || hl_quote.ask == 0.0 {
+                    continue;
+                }
+                let (hl_bid, hl_ask) = (hl_quote.bid, hl_quote.ask);
+
+                // Off the hot path (0.5 Hz), so it's worth pricing the hedge
+                // leg against real depth when the venue's quote carries it,
+                // rather than assuming the fill size clears at top-of-book
+                let current_profit = state.evaluator.recalculate_profit_vwap_raw(
+                    snapshot.side,
+                    snapshot.price,
+                    snapshot.size,
+                    &hl_quote.bid_levels,
+                    &hl_quote.ask_levels,
+                    hl_bid,
+                    hl_ask,
+                );
+                let profit_change = current_profit - snapshot.initial_profit_bps;
+                let age_ms = snapshot.placed_at.elapsed().as_millis();
+
+                // Match whatever `current_profit` above actually priced the
+                // hedge leg against, so this log line doesn't show a profit
+                // number next to a price it wasn't computed from
+                let hedge_price = match snapshot.side {
+                    OrderSide::Buy => crate::strategy::opportunity::vwap_execution_price(&hl_quote.bid_levels, snapshot.size).unwrap_or(hl_bid),
+                    OrderSide::Sell => crate::strategy::opportunity::vwap_execution_price(&hl_quote.ask_levels, snapshot.size).unwrap_or(hl_ask),
+                };
+
+                info!(
+                    "[PROFIT] {} Current: {:.2} bps (initial: {:.2}, change: {:+.2}) | PAC: ${:.4} | HL: ${:.4} | Age: {:.3}s",
+                    symbol,
+                    current_profit,
+                    snapshot.initial_profit_bps,
+                    profit_change,
+                    snapshot.price,
+                    hedge_price,
+                    age_ms as f64 / 1000.0
+                );
             }
+        }
+    }
 
-            let current_profit = self.evaluator.recalculate_profit_raw(
-                snapshot.side,
-                snapshot.price,
-                hl_bid,
-                hl_ask,
-            );
-            let profit_change = current_profit - snapshot.initial_profit_bps;
-            let age_ms = snapshot.placed_at.elapsed().as_millis();
+    /// Periodic hot-path latency report - runs at 0.2 Hz (every 5 seconds),
+    /// independent of order status so an idle bot still confirms the loop is
+    /// within budget
+    pub async fn run_latency_reporter(&self) {
+        let mut report_interval = interval(Duration::from_secs(5));
 
-            let hedge_price = match snapshot.side {
-                OrderSide::Buy => hl_bid,
-                OrderSide::Sell => hl_ask,
-            };
+        loop {
+            report_interval.tick().await;
+
+            let summary = self.hot_path_profiler.summary();
+            if summary.iteration_count == 0 {
+                continue;
+            }
 
             info!(
-                "[PROFIT] Current: {:.2} bps (initial: {:.2}, change: {:+.2}) | PAC: ${:.4} | HL: ${:.4} | Age: {:.3}s",
-                current_profit,
-                snapshot.initial_profit_bps,
-                profit_change,
-                snapshot.price,
-                hedge_price,
-                age_ms as f64 / 1000.0
+                "[LATENCY] monitor loop ({} samples): p50={}us p90={}us p99={}us max={}us, {} overran the {:?} budget",
+                summary.iteration_count,
+                summary.iteration_p50_us,
+                summary.iteration_p90_us,
+                summary.iteration_p99_us,
+                summary.iteration_max_us,
+                summary.iterations_over_budget,
+                self.hot_path_profiler.budget,
             );
+
+            if summary.cancel_count > 0 {
+                info!(
+                    "[LATENCY] cancel round-trip ({} samples): p50={}us p90={}us p99={}us max={}us",
+                    summary.cancel_count, summary.cancel_p50_us, summary.cancel_p90_us, summary.cancel_p99_us, summary.cancel_max_us,
+                );
+            }
         }
     }
 
     /// Check if order has fills (called from cancellation handler, not hot path)
-    async fn check_for_fills(&self) -> FillCheckResult {
+    async fn check_for_fills(&self, symbol_state: &SymbolState) -> FillCheckResult {
         // Get client_order_id from full state (only in cancellation handler)
-        let state = self.bot_state.read().await;
+        let state = symbol_state.bot_state.read().await;
         let client_order_id = match &state.active_order {
             Some(order) => order.client_order_id.clone(),
             None => return FillCheckResult::NotFound,
@@ -411,24 +973,196 @@ impl OrderMonitorService {
         }
     }
 
+    /// Periodic poll that folds any newly observed filled delta into
+    /// `FillAccumulator`, which emits a hedge request once the accumulated
+    /// chunk crosses `config.hedge_chunk_notional_usd`, the order fully
+    /// fills, or a new delta arrives after `config.hedge_chunk_max_latency_ms`
+    /// has passed since the chunk's first delta - instead of waiting for the order to reach a terminal fill before
+    /// hedging anything.
+    ///
+    /// Runs at the slow heartbeat cadence (`config.pacifica_rest_poll_interval_secs`)
+    /// while `detection_mode` reads `WebSocket` or isn't wired up, and at
+    /// `ConnectivitySupervisor`'s aggressive cadence
+    /// (`config.rest_poll_aggressive_interval_ms`) while it reads `Rest` -
+    /// i.e. whenever the WS fill-detection layer is considered stale or
+    /// disconnected, since this poller is the only REST fill-detection path
+    /// in this tree.
+    pub async fn run_fill_poller(&self) {
+        let heartbeat = Duration::from_secs(self.config.pacifica_rest_poll_interval_secs);
+        let aggressive = Duration::from_millis(self.config.rest_poll_aggressive_interval_ms);
+        let mut poll_interval = interval(heartbeat);
+        let mut escalated = false;
+
+        loop {
+            poll_interval.tick().await;
+
+            let should_escalate = matches!(
+                self.detection_mode.as_ref().map(|m| DetectionMode::from(m.load(Ordering::Acquire))),
+                Some(DetectionMode::Rest)
+            );
+            if should_escalate != escalated {
+                escalated = should_escalate;
+                poll_interval = interval(if escalated { aggressive } else { heartbeat });
+            }
+
+            let active: Vec<(String, SymbolState)> = self
+                .symbols
+                .iter()
+                .filter(|entry| {
+                    let status = entry.value().atomic_status.load(Ordering::Acquire);
+                    status == AtomicBotStatus::OrderPlaced as u8 || status == AtomicBotStatus::Filled as u8
+                })
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
+                .collect();
+
+            for (symbol, state) in active {
+                if let Some(delta) = self.poll_and_hedge_delta(&symbol, &state).await {
+                    debug!("[FILL_POLL] {} incremental fill of {} forwarded for hedging", symbol, delta);
+                }
+            }
+        }
+    }
+
+    /// Poll `get_open_orders`, record any newly-observed fill against
+    /// `BotState`, and fold the delta into `fill_accumulator`'s pending chunk
+    /// for this order. Forwards the batched chunk down `hedge_tx` once it
+    /// dispatches (chunk notional crossed, the order fully filled, or this
+    /// delta arrived after the chunk's max latency had passed), returning the size hedged, if any - rather
+    /// than forwarding every individual delta as its own hedge leg.
+    async fn poll_and_hedge_delta(&self, symbol: &str, symbol_state: &SymbolState) -> Option<f64> {
+        let (client_order_id, ledger_side, ledger_price) = {
+            let state = symbol_state.bot_state.read().await;
+            let active = state.active_order.as_ref()?;
+            (active.client_order_id.clone(), active.side, active.price)
+        };
+
+        let orders = self.pacifica_trading.get_open_orders().await.ok()?;
+        let order = orders.iter().find(|o| o.client_order_id == client_order_id)?;
+        let cumulative_filled: f64 = fast_float::parse(&order.filled_amount).unwrap_or(0.0);
+
+        // Gate through the shared dedup ledger before touching `BotState` at
+        // all - a duplicate or stale poll of an already-seen cumulative
+        // amount is dropped here, the same no-op `BotState::mark_filled`
+        // would otherwise compute independently
+        self.fill_ledger.record(LedgerFillEvent {
+            cloid: client_order_id.clone(),
+            seq: 0,
+            cumulative_filled,
+            avg_price: ledger_price,
+            side: ledger_side,
+            source: FillSource::Rest,
+            detect_ts: Instant::now(),
+        })?;
+
+        let (side, price, delta, is_full_fill) = {
+            let mut state = symbol_state.bot_state.write().await;
+            let active = state.active_order.as_ref()?;
+            let side = active.side;
+            let price = active.price;
+            let delta = state.mark_filled(&client_order_id, cumulative_filled, side);
+            (side, price, delta, matches!(state.status, BotStatus::Filled))
+        };
+
+        let chunk = self.fill_accumulator.accumulate(&client_order_id, side, delta, price, is_full_fill)?;
+
+        self.send_hedge_request(symbol, symbol_state, chunk.side, chunk.size, chunk.avg_price, chunk.detected_at)
+            .await;
+        Some(chunk.size)
+    }
+
+    /// Hedge whatever `fill_accumulator` has pending for the active order
+    /// before it gets cleared out from under a cancellation - called from the
+    /// cancellation handler once a partially filled order is about to be
+    /// cancelled, so an accumulated-but-not-yet-chunk-sized fill isn't left
+    /// waiting for a dispatch trigger that will never come once the order is
+    /// gone.
+    async fn hedge_residual_before_cancel(&self, symbol_state: &SymbolState, cumulative_filled: f64) {
+        let client_order_id = {
+            let state = symbol_state.bot_state.read().await;
+            match state.active_order.as_ref() {
+                Some(order) => order.client_order_id.clone(),
+                None => return,
+            }
+        };
+
+        let (symbol, side, price, delta) = {
+            let mut state = symbol_state.bot_state.write().await;
+            let Some(order) = state.active_order.as_ref() else {
+                return;
+            };
+            let symbol = order.symbol.clone();
+            let side = order.side;
+            let price = order.price;
+            let delta = state.mark_filled(&client_order_id, cumulative_filled, side);
+            (symbol, side, price, delta)
+        };
+
+        // Fold the final observed delta in (a no-op if there wasn't one) and
+        // take whichever of the two returns a chunk: `accumulate` itself if
+        // this delta happened to cross a dispatch threshold, or a forced
+        // flush of whatever was already pending otherwise. Never call both,
+        // or a chunk `accumulate` already dispatched (and cleared) would be
+        // silently dropped instead of double-sent.
+        let dispatched = if delta > 0.0 {
+            self.fill_accumulator.accumulate(&client_order_id, side, delta, price, false)
+        } else {
+            None
+        };
+        let Some(chunk) = dispatched.or_else(|| self.fill_accumulator.force_flush(&client_order_id)) else {
+            return;
+        };
+
+        info!("[CANCEL] hedging {} unhedged unit(s) before clearing the cancelled order", chunk.size);
+        self.send_hedge_request(&symbol, symbol_state, chunk.side, chunk.size, chunk.avg_price, chunk.detected_at)
+            .await;
+    }
+
+    /// Capture the current price epochs and forward a hedge request for
+    /// `size` units of `side` filled at `price`, first detected at
+    /// `detected_at`. Always tagged `FillSource::Rest` - `run_fill_poller` is
+    /// the only fill-detection path wired up in this tree (see
+    /// `ConnectivitySupervisor`'s doc comment); a future WebSocket
+    /// fill-detection layer would tag its own requests `FillSource::WebSocket`.
+    #[allow(clippy::too_many_arguments)]
+    async fn send_hedge_request(
+        &self,
+        symbol: &str,
+        symbol_state: &SymbolState,
+        side: OrderSide,
+        size: f64,
+        price: f64,
+        detected_at: Instant,
+    ) {
+        let pacifica_epoch = symbol_state.pacifica_prices.lock().epoch;
+        let hyperliquid_epoch = symbol_state.hyperliquid_prices.lock().epoch;
+        if symbol_state
+            .hedge_tx
+            .send((side, size, price, pacifica_epoch, hyperliquid_epoch, detected_at, FillSource::Rest))
+            .await
+            .is_err()
+        {
+            warn!("[FILL_POLL] {} hedge_tx closed - dropping hedge request for {} {}", symbol, side.as_str(), size);
+        }
+    }
+
     /// Refresh prices from both exchanges in parallel
-    async fn refresh_prices_parallel(&self) {
+    async fn refresh_prices_parallel(&self, symbol: &str, symbol_state: &SymbolState) {
         let pac_future = self.pacifica_trading.get_best_bid_ask_rest(
-            &self.config.symbol,
+            symbol,
             self.config.agg_level,
         );
-        let hl_future = self.hyperliquid_trading.get_l2_snapshot(&self.config.symbol);
+        let hl_future = self.hyperliquid_trading.get_l2_snapshot(symbol);
 
         let (pac_result, hl_result) = tokio::join!(pac_future, hl_future);
 
         if let Ok(Some((bid, ask))) = pac_result {
-            *self.pacifica_prices.lock() = (bid, ask);
-            debug!("[REFRESH] Pacifica: bid=${:.6}, ask=${:.6}", bid, ask);
+            symbol_state.pacifica_prices.lock().update(bid, ask);
+            debug!("[REFRESH] {} Pacifica: bid=${:.6}, ask=${:.6}", symbol, bid, ask);
         }
 
         if let Ok(Some((bid, ask))) = hl_result {
-            *self.hyperliquid_prices.lock() = (bid, ask);
-            debug!("[REFRESH] Hyperliquid: bid=${:.6}, ask=${:.6}", bid, ask);
+            symbol_state.hyperliquid_prices.lock().update(bid, ask);
+            debug!("[REFRESH] {} Hyperliquid: bid=${:.6}, ask=${:.6}", symbol, bid, ask);
         }
     }
 }
@@ -445,11 +1179,23 @@ enum FillCheckResult {
 // HELPER: Update atomic status when BotState changes
 // ============================================================================
 
-/// Call this whenever BotState.status changes to keep atomic in sync
+/// Call this whenever BotState.status changes to keep atomic in sync.
+///
+/// `publisher`/`snapshot` are optional: pass both to also fan the transition
+/// out to any connected state-feed dashboards, or `None` to just update the
+/// atomic as before.
 #[inline]
-pub fn sync_atomic_status(atomic: &AtomicU8, status: &BotStatus) {
+pub fn sync_atomic_status(
+    atomic: &AtomicU8,
+    status: &BotStatus,
+    publisher: Option<(&crate::services::state_feed::StatePublisher, StateSnapshot)>,
+) {
     let atomic_val = AtomicBotStatus::from(status) as u8;
     atomic.store(atomic_val, Ordering::Release);
+
+    if let Some((publisher, snapshot)) = publisher {
+        publisher.publish(snapshot.into_message(format!("status -> {:?}", status)));
+    }
 }
 
 /// Call this when placing a new order to update snapshot
@@ -474,8 +1220,17 @@ pub fn update_order_snapshot(
 // STARTUP HELPER
 // ============================================================================
 
-/// Spawn all monitor tasks
-pub fn spawn_monitor_tasks(service: Arc<OrderMonitorService>, cancel_rx: mpsc::Receiver<CancelRequest>) {
+/// Spawn all monitor tasks, plus the fill poller and one hedge execution task
+/// per registered symbol that drives that symbol's fills (off its own
+/// `hedge_rx`, paired up by symbol in `hedge_receivers`) to a fully-hedged or
+/// fully-rolled-back outcome
+pub fn spawn_monitor_tasks(
+    service: Arc<OrderMonitorService>,
+    cancel_rx: mpsc::Receiver<CancelRequest>,
+    metrics: Arc<crate::metrics::Metrics>,
+    store: Arc<crate::storage::Storage>,
+    hedge_receivers: Vec<(String, mpsc::Receiver<FillEvent>)>,
+) {
     // Hot path monitor (1kHz)
     let service_clone = Arc::clone(&service);
     tokio::spawn(async move {
@@ -493,4 +1248,55 @@ pub fn spawn_monitor_tasks(service: Arc<OrderMonitorService>, cancel_rx: mpsc::R
     tokio::spawn(async move {
         service_clone.run_profit_logger().await;
     });
+
+    // Hot-path latency reporter (0.2 Hz)
+    let service_clone = Arc::clone(&service);
+    tokio::spawn(async move {
+        service_clone.run_latency_reporter().await;
+    });
+
+    // Fill-detection -> hedge latency reporter (0.2 Hz), broken out by fill
+    // source (WebSocket vs REST); owned by `Metrics` itself rather than
+    // `OrderMonitorService` since it only reads `metrics.fill_latency`
+    let metrics_clone = Arc::clone(&metrics);
+    tokio::spawn(async move {
+        let mut report_interval = interval(Duration::from_secs(5));
+        loop {
+            report_interval.tick().await;
+            metrics_clone.fill_latency.log_summary();
+        }
+    });
+
+    // Incremental fill poller (emits a hedge request as soon as a filled
+    // delta clears the configured minimum, instead of waiting for a terminal fill)
+    let service_clone = Arc::clone(&service);
+    tokio::spawn(async move {
+        service_clone.run_fill_poller().await;
+    });
+
+    // Hedge execution: one HedgeService per registered symbol, each consuming
+    // that symbol's fills and driving the optimistic hedge + rollback path
+    for (symbol, hedge_rx) in hedge_receivers {
+        let Some(state) = service.symbol_state(&symbol) else {
+            warn!("[MONITOR] skipping hedge service for unregistered symbol {}", symbol);
+            continue;
+        };
+        let hedge_service = crate::services::hedge::HedgeService::new(
+            symbol.clone(),
+            state.bot_state.clone(),
+            service.config.clone(),
+            state.evaluator.clone(),
+            service.hyperliquid_trading.clone(),
+            service.pacifica_trading.clone(),
+            state.pacifica_prices.clone(),
+            state.hyperliquid_prices.clone(),
+            metrics.clone(),
+            store.clone(),
+            service.hedge_feed.clone(),
+            hedge_rx,
+        );
+        tokio::spawn(async move {
+            hedge_service.run().await;
+        });
+    }
 }
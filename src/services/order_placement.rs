@@ -1,12 +1,18 @@
 use std::sync::Arc;
 use std::time::Instant;
+use dashmap::DashMap;
+use parking_lot::Mutex;
 use tokio::sync::{mpsc, RwLock};
 use colored::Colorize;
 
+use crate::bot::risk::RiskBudget;
 use crate::bot::{BotState, ActiveOrder};
 use crate::config::Config;
 use crate::connector::pacifica::{PacificaTrading, OrderSide as PacificaOrderSide};
-use crate::strategy::{Opportunity, OrderSide};
+use crate::metrics::Metrics;
+use crate::services::orderbook::TimestampedPrice;
+use crate::services::state_feed::{StatePublisher, StateSnapshot};
+use crate::strategy::{Opportunity, OpportunityEvaluator, OrderSide};
 use crate::util::rate_limit::RateLimitTracker;
 
 // Macro for timestamped colored output
@@ -22,23 +28,81 @@ macro_rules! tprintln {
 /// Order placement request
 #[derive(Debug, Clone)]
 pub struct OrderPlacementRequest {
+    pub symbol: String,
     pub opportunity: Opportunity,
     pub pac_bid: f64,
     pub pac_ask: f64,
 }
 
+/// Everything order placement needs that's specific to one symbol, keyed into
+/// `OrderPlacementService::symbols`. Mirrors
+/// `crate::services::order_monitor::SymbolState` - letting one service
+/// instance place orders for every registered pair instead of requiring a
+/// fully duplicated `OrderPlacementService` per symbol.
+#[derive(Clone)]
+struct SymbolPlacementState {
+    bot_state: Arc<RwLock<BotState>>,
+    pacifica_prices: Arc<Mutex<TimestampedPrice>>,
+    hyperliquid_prices: Arc<Mutex<TimestampedPrice>>,
+    evaluator: OpportunityEvaluator,
+}
+
 /// Order placement service
 ///
 /// Handles order placement asynchronously in a dedicated task.
 /// This prevents blocking the main evaluation loop during network I/O.
 pub struct OrderPlacementService {
-    pub bot_state: Arc<RwLock<BotState>>,
+    symbols: DashMap<String, SymbolPlacementState>,
     pub pacifica_trading: Arc<PacificaTrading>,
     pub config: Config,
     pub order_rx: mpsc::Receiver<OrderPlacementRequest>,
+    pub metrics: Arc<Metrics>,
+    /// Portfolio-wide gross notional / net delta budget, shared across every
+    /// symbol a `Supervisor` runs; an unconstrained budget for a standalone
+    /// single-symbol bot
+    pub risk_budget: Arc<RiskBudget>,
+    /// Fans the new-order transition out to connected state-feed dashboards
+    pub state_publisher: Arc<StatePublisher>,
 }
 
 impl OrderPlacementService {
+    /// Create a new, symbol-less order placement service. Call
+    /// `register_symbol` for each pair this instance should place orders for.
+    pub fn new(
+        pacifica_trading: Arc<PacificaTrading>,
+        config: Config,
+        order_rx: mpsc::Receiver<OrderPlacementRequest>,
+        metrics: Arc<Metrics>,
+        risk_budget: Arc<RiskBudget>,
+        state_publisher: Arc<StatePublisher>,
+    ) -> Self {
+        Self {
+            symbols: DashMap::new(),
+            pacifica_trading,
+            config,
+            order_rx,
+            metrics,
+            risk_budget,
+            state_publisher,
+        }
+    }
+
+    /// Register a symbol's state so placement requests for it can be
+    /// served. Replaces any existing entry for the same symbol.
+    pub fn register_symbol(
+        &self,
+        symbol: String,
+        bot_state: Arc<RwLock<BotState>>,
+        pacifica_prices: Arc<Mutex<TimestampedPrice>>,
+        hyperliquid_prices: Arc<Mutex<TimestampedPrice>>,
+        evaluator: OpportunityEvaluator,
+    ) {
+        self.symbols.insert(
+            symbol,
+            SymbolPlacementState { bot_state, pacifica_prices, hyperliquid_prices, evaluator },
+        );
+    }
+
     pub async fn run(mut self) {
         let mut rate_limit = RateLimitTracker::new();
 
@@ -51,7 +115,7 @@ impl OrderPlacementService {
                         let remaining = rate_limit.remaining_backoff_secs();
                         tprintln!(
                             "{} ⚠ Skipping order placement (rate limit backoff, {:.1}s remaining)",
-                            format!("[{} ORDER]", self.config.symbol).bright_yellow().bold(),
+                            format!("[{} ORDER]", request.symbol).bright_yellow().bold(),
                             remaining
                         );
                         continue;
@@ -68,11 +132,35 @@ impl OrderPlacementService {
     }
 
     async fn place_order(&self, request: OrderPlacementRequest, rate_limit: &mut RateLimitTracker) {
+        let symbol = request.symbol;
         let opp = request.opportunity;
-        
+
+        let Some(symbol_state) = self.symbols.get(&symbol).map(|entry| entry.clone()) else {
+            tprintln!(
+                "{} {} Skipping order: symbol is not registered with this placement service",
+                format!("[{} ORDER]", symbol).bright_yellow().bold(),
+                "⚠".yellow().bold()
+            );
+            return;
+        };
+
+        let notional_usd = opp.size * opp.pacifica_price;
+        let signed_delta_usd = match opp.direction {
+            OrderSide::Buy => notional_usd,
+            OrderSide::Sell => -notional_usd,
+        };
+        if !self.risk_budget.allows(&symbol, notional_usd, signed_delta_usd) {
+            tprintln!(
+                "{} {} Skipping order: would exceed the portfolio-wide risk budget",
+                format!("[{} ORDER]", symbol).bright_yellow().bold(),
+                "⚠".yellow().bold()
+            );
+            return;
+        }
+
         tprintln!(
             "{} Placing {} on Pacifica...",
-            format!("[{} ORDER]", self.config.symbol).bright_yellow().bold(),
+            format!("[{} ORDER]", symbol).bright_yellow().bold(),
             opp.direction.as_str().bright_yellow().bold()
         );
 
@@ -81,9 +169,10 @@ impl OrderPlacementService {
             OrderSide::Sell => PacificaOrderSide::Sell,
         };
 
-        match self.pacifica_trading
+        let placement_started_at = Instant::now();
+        let placement_result = self.pacifica_trading
             .place_limit_order(
-                &self.config.symbol,
+                &symbol,
                 pacifica_side,
                 opp.size,
                 Some(opp.pacifica_price),
@@ -91,8 +180,10 @@ impl OrderPlacementService {
                 Some(request.pac_bid),
                 Some(request.pac_ask),
             )
-            .await
-        {
+            .await;
+        self.metrics.maker_order_placement_latency.observe(placement_started_at.elapsed());
+
+        match placement_result {
             Ok(order_data) => {
                 rate_limit.record_success();
 
@@ -100,7 +191,7 @@ impl OrderPlacementService {
                     let order_id = order_data.order_id.unwrap_or(0);
                     tprintln!(
                         "{} {} Placed {} #{} @ {} | cloid: {}...{}",
-                        format!("[{} ORDER]", self.config.symbol).bright_yellow().bold(),
+                        format!("[{} ORDER]", symbol).bright_yellow().bold(),
                         "✓".green().bold(),
                         opp.direction.as_str().bright_yellow(),
                         order_id,
@@ -111,21 +202,34 @@ impl OrderPlacementService {
 
                     let active_order = ActiveOrder {
                         client_order_id,
-                        symbol: self.config.symbol.clone(),
+                        symbol: symbol.clone(),
                         side: opp.direction,
                         price: opp.pacifica_price,
                         size: opp.size,
                         initial_profit_bps: opp.initial_profit_bps,
                         placed_at: Instant::now(),
+                        filled_size: 0.0,
+                        unhedged_size: 0.0,
                     };
 
                     // Update bot state
-                    let mut state = self.bot_state.write().await;
+                    let mut state = symbol_state.bot_state.write().await;
                     state.set_active_order(active_order);
+
+                    let snapshot = StateSnapshot::capture(
+                        &state,
+                        &symbol_state.pacifica_prices.lock(),
+                        &symbol_state.hyperliquid_prices.lock(),
+                        &symbol_state.evaluator,
+                    );
+                    self.state_publisher.publish(snapshot.into_message("order placed"));
+
+                    // Surface this symbol's new exposure to the rest of the portfolio
+                    self.risk_budget.update_exposure(&symbol, notional_usd, signed_delta_usd);
                 } else {
                     tprintln!(
                         "{} {} Order placed but no client_order_id returned",
-                        format!("[{} ORDER]", self.config.symbol).bright_yellow().bold(),
+                        format!("[{} ORDER]", symbol).bright_yellow().bold(),
                         "✗".red().bold()
                     );
                 }
@@ -137,7 +241,7 @@ impl OrderPlacementService {
                     let backoff_secs = rate_limit.get_backoff_secs();
                     tprintln!(
                         "{} {} Failed to place order: Rate limit exceeded. Backing off for {}s (attempt #{})",
-                        format!("[{} ORDER]", self.config.symbol).bright_yellow().bold(),
+                        format!("[{} ORDER]", symbol).bright_yellow().bold(),
                         "⚠".yellow().bold(),
                         backoff_secs,
                         rate_limit.consecutive_errors()
@@ -145,7 +249,7 @@ impl OrderPlacementService {
                 } else {
                     tprintln!(
                         "{} {} Failed to place order: {}",
-                        format!("[{} ORDER]", self.config.symbol).bright_yellow().bold(),
+                        format!("[{} ORDER]", symbol).bright_yellow().bold(),
                         "✗".red().bold(),
                         e
                     );
@@ -1,9 +1,117 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use anyhow::{Context, Result};
 use colored::Colorize;
+use parking_lot::Mutex;
 
 use crate::connector::pacifica::{OrderbookClient as PacificaOrderbookClient, OrderbookConfig as PacificaOrderbookConfig};
 use crate::connector::hyperliquid::{OrderbookClient as HyperliquidOrderbookClient, OrderbookConfig as HyperliquidOrderbookConfig};
+use crate::strategy::PriceLevel;
+
+/// Shared bid/ask quote for a venue, timestamped so stale books can be detected
+///
+/// Replaces the bare `(f64, f64)` tuple previously used for shared price
+/// state: a silently-stalled websocket would otherwise leave the evaluator
+/// trading against a frozen book with no way to tell. Widened to optionally
+/// carry full depth (`bid_levels`/`ask_levels`) alongside the top-of-book
+/// `bid`/`ask`, so a VWAP-aware caller (`OpportunityEvaluator::evaluate_*_opportunity_vwap`,
+/// `recalculate_profit_vwap_raw`) can price execution against the real book
+/// instead of assuming top-of-book liquidity; empty when only a top-of-book
+/// quote is known (e.g. from a REST poll that doesn't carry full depth).
+#[derive(Debug, Clone)]
+pub struct TimestampedPrice {
+    pub bid: f64,
+    pub ask: f64,
+    pub last_update: Instant,
+    /// Monotonically increasing counter bumped on every write, so a hedge
+    /// task can tell whether its captured view of this quote is still current
+    pub epoch: u64,
+    /// Bid levels sorted best-to-worst, as `(price, size)`; empty if this
+    /// quote was last updated from a top-of-book-only source
+    pub bid_levels: Vec<PriceLevel>,
+    /// Ask levels sorted best-to-worst, as `(price, size)`; empty if this
+    /// quote was last updated from a top-of-book-only source
+    pub ask_levels: Vec<PriceLevel>,
+}
+
+impl TimestampedPrice {
+    /// Create a zeroed quote, timestamped as of now
+    pub fn new() -> Self {
+        Self {
+            bid: 0.0,
+            ask: 0.0,
+            last_update: Instant::now(),
+            epoch: 0,
+            bid_levels: Vec::new(),
+            ask_levels: Vec::new(),
+        }
+    }
+
+    /// Overwrite the top-of-book quote, bump `last_update` to now, and
+    /// advance the epoch. Clears any previously-known depth - callers with
+    /// full depth should use `update_with_depth` instead.
+    pub fn update(&mut self, bid: f64, ask: f64) {
+        self.bid = bid;
+        self.ask = ask;
+        self.last_update = Instant::now();
+        self.epoch = self.epoch.wrapping_add(1);
+        self.bid_levels.clear();
+        self.ask_levels.clear();
+    }
+
+    /// Overwrite the top-of-book quote, bump `last_update` to now, and
+    /// advance the epoch, without touching `bid_levels`/`ask_levels`
+    ///
+    /// For a venue that only refreshes depth periodically (see
+    /// `HyperliquidOrderbookService::run`): the last-known depth is still a
+    /// better VWAP input than none at all on the ticks in between, as long as
+    /// a caller cares to gate on `age()`/`is_stale` for freshness.
+    pub fn update_top_of_book(&mut self, bid: f64, ask: f64) {
+        self.bid = bid;
+        self.ask = ask;
+        self.last_update = Instant::now();
+        self.epoch = self.epoch.wrapping_add(1);
+    }
+
+    /// Overwrite the quote along with full depth on both sides, bump
+    /// `last_update` to now, and advance the epoch
+    pub fn update_with_depth(&mut self, bid: f64, ask: f64, bid_levels: Vec<PriceLevel>, ask_levels: Vec<PriceLevel>) {
+        self.bid = bid;
+        self.ask = ask;
+        self.last_update = Instant::now();
+        self.epoch = self.epoch.wrapping_add(1);
+        self.bid_levels = bid_levels;
+        self.ask_levels = ask_levels;
+    }
+
+    /// Time elapsed since the last update
+    pub fn age(&self) -> Duration {
+        self.last_update.elapsed()
+    }
+
+    /// Whether this quote is older than `max_age`, or still zeroed (never received)
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        (self.bid == 0.0 && self.ask == 0.0) || self.age() > max_age
+    }
+
+    /// Whether this quote is crossed (bid at or above ask) - a book state that
+    /// should never be traded against, most often seen from a disconnected or
+    /// desynchronized feed rather than a genuine market condition
+    pub fn is_crossed(&self) -> bool {
+        self.bid > 0.0 && self.ask > 0.0 && self.bid >= self.ask
+    }
+
+    /// Whether this quote is fresh enough and not crossed - safe to trade against
+    pub fn is_valid(&self, max_age: Duration) -> bool {
+        !self.is_stale(max_age) && !self.is_crossed()
+    }
+}
+
+impl Default for TimestampedPrice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 // Macro for timestamped colored output
 macro_rules! tprintln {
@@ -15,12 +123,129 @@ macro_rules! tprintln {
     }};
 }
 
+/// Gate a captured hedge view on quote freshness immediately before submitting it
+///
+/// `captured_epoch` is the venue's `TimestampedPrice::epoch` read at the moment
+/// an opportunity was evaluated; `current` is the same venue's live quote,
+/// re-read just before the taker order is sent. If the live epoch has advanced
+/// by more than `epoch_tolerance` since it was captured, or the quote has gone
+/// stale per `max_age`, the captured view can no longer be trusted: logs a
+/// `[STALE_VIEW]` line and returns `false`. What to do with a stale view is
+/// left to the caller.
+pub fn check_hedge_epoch_freshness(
+    captured_epoch: u64,
+    current: &TimestampedPrice,
+    epoch_tolerance: u64,
+    max_age: std::time::Duration,
+) -> bool {
+    let epoch_drift = current.epoch.wrapping_sub(captured_epoch);
+    if epoch_drift > epoch_tolerance {
+        tprintln!(
+            "{} stale view - quote advanced {} epochs (tolerance {}) since capture",
+            "[STALE_VIEW]".red().bold(),
+            epoch_drift,
+            epoch_tolerance
+        );
+        return false;
+    }
+    if current.is_stale(max_age) {
+        tprintln!(
+            "{} stale view - quote is {:.1}s old (max {:.1}s)",
+            "[STALE_VIEW]".red().bold(),
+            current.age().as_secs_f64(),
+            max_age.as_secs_f64()
+        );
+        return false;
+    }
+    true
+}
+
+/// Gate opportunity evaluation on quote freshness
+///
+/// Exported through `services` for the opportunity-evaluation path; nothing in
+/// this crate calls it. Returns `true` if both venues' quotes are fresh enough to
+/// trade against; if either has gone stale (or never received a quote), logs
+/// a warning and returns `false` so the caller can skip this cycle instead of
+/// pricing a hedge off a frozen book.
+pub fn check_price_freshness(
+    pacifica: &TimestampedPrice,
+    hyperliquid: &TimestampedPrice,
+    max_age: std::time::Duration,
+) -> bool {
+    if pacifica.is_stale(max_age) {
+        tprintln!(
+            "{} Pacifica quote is stale ({:.1}s old) - suppressing new opportunities",
+            "[STALENESS]".red().bold(),
+            pacifica.age().as_secs_f64()
+        );
+        return false;
+    }
+    if hyperliquid.is_stale(max_age) {
+        tprintln!(
+            "{} Hyperliquid quote is stale ({:.1}s old) - suppressing new opportunities",
+            "[STALENESS]".red().bold(),
+            hyperliquid.age().as_secs_f64()
+        );
+        return false;
+    }
+    true
+}
+
+/// One named input to a `PriceFeed` - typically a venue's primary WebSocket
+/// stream, or a slower REST-polled fallback that keeps updating the same
+/// venue's quote when the primary stalls
+#[derive(Clone)]
+pub struct PriceSource {
+    pub name: &'static str,
+    pub price: Arc<Mutex<TimestampedPrice>>,
+}
+
+impl PriceSource {
+    pub fn new(name: &'static str, price: Arc<Mutex<TimestampedPrice>>) -> Self {
+        Self { name, price }
+    }
+}
+
+/// Aggregates redundant price sources for a single venue (e.g. primary WS plus
+/// a REST-polled fallback) and resolves them to the single freshest valid quote.
+///
+/// A dropped WebSocket with no fallback blinds the evaluator until reconnection;
+/// this lets a slower, independent source keep the venue quotable in the
+/// meantime. Because `resolve` always picks the most recently updated source
+/// that passes `TimestampedPrice::is_valid`, a recovered primary naturally wins
+/// back out over the fallback the next time it updates - no explicit handoff
+/// is needed.
+pub struct PriceFeed {
+    sources: Vec<PriceSource>,
+}
+
+impl PriceFeed {
+    pub fn new(sources: Vec<PriceSource>) -> Self {
+        Self { sources }
+    }
+
+    /// The freshest quote among sources that are neither stale nor crossed, or
+    /// `None` if every source for this venue has gone invalid
+    pub fn resolve(&self, max_age: Duration) -> Option<TimestampedPrice> {
+        self.sources
+            .iter()
+            .map(|source| source.price.lock().clone())
+            .filter(|quote| quote.is_valid(max_age))
+            .max_by_key(|quote| quote.last_update)
+    }
+
+    /// Whether at least one source currently has a valid quote for this venue
+    pub fn is_valid(&self, max_age: Duration) -> bool {
+        self.resolve(max_age).is_some()
+    }
+}
+
 /// Pacifica orderbook service
 ///
 /// Subscribes to Pacifica orderbook WebSocket and updates shared price state.
 /// Provides real-time bid/ask prices for opportunity evaluation.
 pub struct PacificaOrderbookService {
-    pub prices: Arc<Mutex<(f64, f64)>>,
+    pub prices: Arc<Mutex<TimestampedPrice>>,
     pub symbol: String,
     pub agg_level: u32,
     pub reconnect_attempts: u32,
@@ -46,13 +271,18 @@ impl PacificaOrderbookService {
         pacifica_ob_client
             .start(move |book_data| {
                 // Extract top of book using zero-copy accessor (optimized for latency)
-                // Note: book_data contains full depth which can be used for VWAP later
                 if let Some((bid_str, ask_str)) = book_data.get_best_bid_ask() {
                     // Parse strings directly without intermediate allocations
                     let bid_price: f64 = bid_str.parse().unwrap_or(0.0);
                     let ask_price: f64 = ask_str.parse().unwrap_or(0.0);
-                    *pac_prices_clone.lock().unwrap() = (bid_price, ask_price);
-                    
+
+                    // Depth isn't needed on the maker (Pacifica) leg today -
+                    // only the hedge leg's book is walked by
+                    // `OpportunityEvaluator::evaluate_*_opportunity_vwap` /
+                    // `recalculate_profit_vwap_raw` - so this stays a
+                    // top-of-book-only update
+                    pac_prices_clone.lock().update(bid_price, ask_price);
+
                     // Notify subscribers of price update (triggers opportunity evaluation + order monitoring)
                     let _ = price_update_tx_clone.send(());
                 }
@@ -69,7 +299,7 @@ impl PacificaOrderbookService {
 /// Subscribes to Hyperliquid orderbook WebSocket and updates shared price state.
 /// Provides real-time bid/ask prices for hedge execution.
 pub struct HyperliquidOrderbookService {
-    pub prices: Arc<Mutex<(f64, f64)>>,
+    pub prices: Arc<Mutex<TimestampedPrice>>,
     pub symbol: String,
     pub reconnect_attempts: u32,
     pub ping_interval_secs: u64,
@@ -92,6 +322,13 @@ impl HyperliquidOrderbookService {
             .context("Failed to create Hyperliquid orderbook client")?;
 
         tprintln!("{} Starting orderbook client", "[HYPERLIQUID_OB]".magenta().bold());
+        // Only current consumer of depth (`run_profit_logger`) reads it at
+        // 0.5 Hz, so re-parsing full depth on every websocket tick would be
+        // pure overhead; re-capture it at roughly that cadence instead and
+        // carry the last-known depth across the top-of-book-only ticks in
+        // between via `update_top_of_book`
+        let depth_refresh_interval = Duration::from_secs(2);
+        let mut last_depth_capture_at = Instant::now() - depth_refresh_interval;
         hyperliquid_ob_client
             .start(move |book_data| {
                 // Extract top of book using zero-copy accessor (optimized for latency)
@@ -99,8 +336,39 @@ impl HyperliquidOrderbookService {
                     // Parse strings directly without intermediate allocations
                     let bid_price: f64 = bid_str.parse().unwrap_or(0.0);
                     let ask_price: f64 = ask_str.parse().unwrap_or(0.0);
-                    *hl_prices_clone.lock().unwrap() = (bid_price, ask_price);
-                    
+
+                    // This is the hedge leg's book, so it's worth carrying
+                    // depth alongside top-of-book: it's what lets
+                    // `OpportunityEvaluator::evaluate_*_opportunity_vwap` and
+                    // `recalculate_profit_vwap_raw` price a fill against the
+                    // real book instead of assuming it all clears at the touch
+                    let refreshed_depth = if last_depth_capture_at.elapsed() >= depth_refresh_interval {
+                        book_data.get_depth()
+                    } else {
+                        None
+                    };
+
+                    if let Some((bid_levels, ask_levels)) = refreshed_depth {
+                        let parse_levels = |levels: &[(String, String)]| -> Vec<PriceLevel> {
+                            levels
+                                .iter()
+                                .map(|(p, s)| (p.parse().unwrap_or(0.0), s.parse().unwrap_or(0.0)))
+                                .collect()
+                        };
+                        hl_prices_clone.lock().update_with_depth(
+                            bid_price,
+                            ask_price,
+                            parse_levels(&bid_levels),
+                            parse_levels(&ask_levels),
+                        );
+                        last_depth_capture_at = Instant::now();
+                    } else {
+                        // Between depth refreshes (or if this snapshot just
+                        // didn't carry depth): move the quote, keep the last
+                        // depth we captured rather than wiping it
+                        hl_prices_clone.lock().update_top_of_book(bid_price, ask_price);
+                    }
+
                     // Notify subscribers of price update (triggers opportunity evaluation + order monitoring)
                     let _ = price_update_tx_clone.send(());
                 }
@@ -111,3 +379,60 @@ impl HyperliquidOrderbookService {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source_at(name: &'static str, bid: f64, ask: f64, age: Duration) -> PriceSource {
+        let mut price = TimestampedPrice::new();
+        price.update(bid, ask);
+        price.last_update = Instant::now() - age;
+        PriceSource::new(name, Arc::new(Mutex::new(price)))
+    }
+
+    #[test]
+    fn test_resolve_picks_freshest_valid_source() {
+        let feed = PriceFeed::new(vec![
+            source_at("rest_poll", 99.0, 100.0, Duration::from_millis(500)),
+            source_at("ws", 99.5, 100.5, Duration::from_millis(10)),
+        ]);
+
+        let resolved = feed.resolve(Duration::from_secs(5)).unwrap();
+        assert_eq!(resolved.bid, 99.5);
+        assert_eq!(resolved.ask, 100.5);
+    }
+
+    #[test]
+    fn test_resolve_falls_back_when_primary_is_stale() {
+        let feed = PriceFeed::new(vec![
+            source_at("ws", 99.5, 100.5, Duration::from_secs(30)),
+            source_at("rest_poll", 99.0, 100.0, Duration::from_millis(500)),
+        ]);
+
+        let resolved = feed.resolve(Duration::from_secs(5)).unwrap();
+        assert_eq!(resolved.bid, 99.0);
+    }
+
+    #[test]
+    fn test_resolve_skips_crossed_book() {
+        let feed = PriceFeed::new(vec![
+            source_at("ws", 100.5, 99.5, Duration::from_millis(10)),
+            source_at("rest_poll", 99.0, 100.0, Duration::from_millis(500)),
+        ]);
+
+        let resolved = feed.resolve(Duration::from_secs(5)).unwrap();
+        assert_eq!(resolved.bid, 99.0);
+    }
+
+    #[test]
+    fn test_resolve_none_when_every_source_invalid() {
+        let feed = PriceFeed::new(vec![
+            source_at("ws", 99.5, 100.5, Duration::from_secs(30)),
+            source_at("rest_poll", 0.0, 0.0, Duration::from_millis(10)),
+        ]);
+
+        assert!(feed.resolve(Duration::from_secs(5)).is_none());
+        assert!(!feed.is_valid(Duration::from_secs(5)));
+    }
+}
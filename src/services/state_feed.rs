@@ -0,0 +1,247 @@
+/// Broadcasts bot/position state changes to connected dashboards, so
+/// operators can watch live profit/age/hedge status remotely instead of
+/// polling `BotState` or scraping `/metrics`.
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use crate::bot::BotState;
+use crate::services::orderbook::TimestampedPrice;
+use crate::strategy::OpportunityEvaluator;
+
+// Macro for timestamped colored output
+macro_rules! tprintln {
+    ($($arg:tt)*) => {{
+        println!("{} {}",
+            chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S%.6fZ").to_string().bright_black(),
+            format!($($arg)*)
+        );
+    }};
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+fn json_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn num_or_null(value: Option<f64>) -> String {
+    match value {
+        Some(v) => v.to_string(),
+        None => "null".to_string(),
+    }
+}
+
+/// A full reference snapshot of current bot/position state, captured at the
+/// moment a change is published: active order, latest Pacifica/Hyperliquid
+/// bid/ask, and current profit bps.
+pub struct StateSnapshot {
+    pub symbol: String,
+    pub status: String,
+    pub active_order_side: Option<&'static str>,
+    pub active_order_price: Option<f64>,
+    pub active_order_size: Option<f64>,
+    pub unhedged_size: Option<f64>,
+    pub pacifica_bid: f64,
+    pub pacifica_ask: f64,
+    pub hyperliquid_bid: f64,
+    pub hyperliquid_ask: f64,
+    pub current_profit_bps: Option<f64>,
+}
+
+impl StateSnapshot {
+    /// Capture a snapshot from the live state and price feeds. `evaluator` is
+    /// used to recompute current profit bps for the active order, if any,
+    /// the same way `OrderMonitorService::monitor_tick` does.
+    pub fn capture(
+        bot_state: &BotState,
+        pacifica_prices: &TimestampedPrice,
+        hyperliquid_prices: &TimestampedPrice,
+        evaluator: &OpportunityEvaluator,
+    ) -> Self {
+        let active = bot_state.active_order.as_ref();
+        let current_profit_bps = active.map(|order| {
+            evaluator.recalculate_profit_raw(order.side, order.price, hyperliquid_prices.bid, hyperliquid_prices.ask)
+        });
+
+        Self {
+            symbol: active.map(|o| o.symbol.clone()).unwrap_or_default(),
+            status: format!("{:?}", bot_state.status),
+            active_order_side: active.map(|o| o.side.as_str()),
+            active_order_price: active.map(|o| o.price),
+            active_order_size: active.map(|o| o.size),
+            unhedged_size: active.map(|o| o.unhedged_size),
+            pacifica_bid: pacifica_prices.bid,
+            pacifica_ask: pacifica_prices.ask,
+            hyperliquid_bid: hyperliquid_prices.bid,
+            hyperliquid_ask: hyperliquid_prices.ask,
+            current_profit_bps,
+        }
+    }
+
+    /// Pair this snapshot with the incremental change that triggered it
+    pub fn into_message(self, change: impl Into<String>) -> StateMessage {
+        StateMessage {
+            change: change.into(),
+            symbol: self.symbol,
+            status: self.status,
+            active_order_side: self.active_order_side,
+            active_order_price: self.active_order_price,
+            active_order_size: self.active_order_size,
+            unhedged_size: self.unhedged_size,
+            pacifica_bid: self.pacifica_bid,
+            pacifica_ask: self.pacifica_ask,
+            hyperliquid_bid: self.hyperliquid_bid,
+            hyperliquid_ask: self.hyperliquid_ask,
+            current_profit_bps: self.current_profit_bps,
+            emitted_at_ms: now_ms(),
+        }
+    }
+}
+
+/// A single state-feed message: the incremental change that triggered it
+/// (e.g. "order placed", "profit deviation cancel", "hedge complete"), plus
+/// the full reference snapshot captured at that moment.
+pub struct StateMessage {
+    pub change: String,
+    pub symbol: String,
+    pub status: String,
+    pub active_order_side: Option<&'static str>,
+    pub active_order_price: Option<f64>,
+    pub active_order_size: Option<f64>,
+    pub unhedged_size: Option<f64>,
+    pub pacifica_bid: f64,
+    pub pacifica_ask: f64,
+    pub hyperliquid_bid: f64,
+    pub hyperliquid_ask: f64,
+    pub current_profit_bps: Option<f64>,
+    pub emitted_at_ms: u64,
+}
+
+impl StateMessage {
+    /// Hand-rolled JSON rendering - the crate has no serde dependency
+    /// elsewhere, so this mirrors `Metrics::render_prometheus`'s hand-rolled
+    /// text-exposition format rather than pulling one in just for this.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"change\":\"{}\",\"symbol\":\"{}\",\"status\":\"{}\",\"active_order_side\":{},\"active_order_price\":{},\"active_order_size\":{},\"unhedged_size\":{},\"pacifica_bid\":{},\"pacifica_ask\":{},\"hyperliquid_bid\":{},\"hyperliquid_ask\":{},\"current_profit_bps\":{},\"emitted_at_ms\":{}}}",
+            json_escape(&self.change),
+            json_escape(&self.symbol),
+            json_escape(&self.status),
+            self.active_order_side.map(|s| format!("\"{}\"", s)).unwrap_or_else(|| "null".to_string()),
+            num_or_null(self.active_order_price),
+            num_or_null(self.active_order_size),
+            num_or_null(self.unhedged_size),
+            self.pacifica_bid,
+            self.pacifica_ask,
+            self.hyperliquid_bid,
+            self.hyperliquid_ask,
+            num_or_null(self.current_profit_bps),
+            self.emitted_at_ms,
+        )
+    }
+}
+
+/// Thin wrapper around a `tokio::sync::broadcast` channel that fans out
+/// `StateMessage`s to every connected dashboard. Cheap to clone - the
+/// underlying sender is reference-counted internally - and safe to call from
+/// any of the hot-path-adjacent tasks that mutate `BotState`.
+#[derive(Clone)]
+pub struct StatePublisher {
+    tx: broadcast::Sender<Arc<StateMessage>>,
+}
+
+impl StatePublisher {
+    /// `capacity` bounds how many messages a slow subscriber can lag behind
+    /// before it starts missing them (surfaced as `RecvError::Lagged`)
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _rx) = broadcast::channel(capacity);
+        Self { tx }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<StateMessage>> {
+        self.tx.subscribe()
+    }
+
+    /// Publish a state message; a no-op if nobody is currently subscribed
+    pub fn publish(&self, message: StateMessage) {
+        let _ = self.tx.send(Arc::new(message));
+    }
+}
+
+/// Serves the live state feed on `GET /state` as a long-lived
+/// `text/event-stream` response - each connected client receives every
+/// `StateMessage` published after it connects, one JSON object per `data:`
+/// line.
+///
+/// Hand-rolled HTTP rather than a full web framework, same as
+/// `MetricsServer`: this is one streaming endpoint for a handful of
+/// dashboards, not a general-purpose API surface, and server-sent events let
+/// a raw socket push messages without having to implement the websocket
+/// handshake/framing ourselves.
+pub struct StateFeedServer {
+    pub publisher: StatePublisher,
+    pub port: u16,
+}
+
+impl StateFeedServer {
+    pub async fn run(self) -> Result<()> {
+        let listener = TcpListener::bind(("0.0.0.0", self.port))
+            .await
+            .with_context(|| format!("Failed to bind state feed server on port {}", self.port))?;
+
+        tprintln!(
+            "{} Serving live state feed on http://0.0.0.0:{}/state",
+            "[STATE_FEED]".magenta().bold(),
+            self.port
+        );
+
+        loop {
+            let (mut socket, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    tprintln!("{} Failed to accept state feed connection: {}", "[STATE_FEED]".red().bold(), e);
+                    continue;
+                }
+            };
+            let mut rx = self.publisher.subscribe();
+            tokio::spawn(async move {
+                let mut buf = [0u8; 1024];
+                // One fixed stream regardless of path/method, so the request
+                // itself just needs draining, not parsing.
+                if socket.read(&mut buf).await.is_err() {
+                    return;
+                }
+
+                let headers = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n";
+                if socket.write_all(headers.as_bytes()).await.is_err() {
+                    return;
+                }
+
+                loop {
+                    match rx.recv().await {
+                        Ok(message) => {
+                            let frame = format!("data: {}\n\n", message.to_json());
+                            if socket.write_all(frame.as_bytes()).await.is_err() {
+                                return;
+                            }
+                        }
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("[STATE_FEED] client lagged, skipped {} messages", skipped);
+                            continue;
+                        }
+                        Err(broadcast::error::RecvError::Closed) => return,
+                    }
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,177 @@
+/// Storage module - durable persistence for fills, hedges and position
+/// snapshots so a restart doesn't lose fill-dedup state or re-hedge an
+/// already-hedged fill
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::strategy::OrderSide;
+
+/// A rehydrated position snapshot row, as last persisted for a symbol
+#[derive(Debug, Clone)]
+pub struct PositionSnapshotRow {
+    pub amount: f64,
+    pub side: String,
+    pub checked_at_ms: u64,
+}
+
+/// Realized PnL reconstructed from the hedges table for one symbol
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SymbolPnl {
+    pub hedge_count: u64,
+    pub avg_realized_spread_bps: f64,
+    pub total_funding_bps: f64,
+}
+
+/// Durable sink for maker fills, their hedges, and the latest position
+/// snapshot per symbol, backed by a local sqlite file
+///
+/// A single `Mutex<Connection>` matches `OpportunityRecorder`'s
+/// `Mutex<File>` sink pattern: writes are infrequent (one per fill/hedge)
+/// so lock contention isn't a concern.
+pub struct Storage {
+    conn: Mutex<Connection>,
+}
+
+impl Storage {
+    /// Open (or create) the sqlite database at `path`, creating tables if absent
+    pub fn new(path: &str) -> Result<Self> {
+        let conn = Connection::open(path).with_context(|| format!("Failed to open storage db at {}", path))?;
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS fills (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                client_order_id TEXT NOT NULL,
+                symbol TEXT NOT NULL,
+                side TEXT NOT NULL,
+                price REAL NOT NULL,
+                size REAL NOT NULL,
+                filled_at_ms INTEGER NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS hedges (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                fill_client_order_id TEXT NOT NULL,
+                symbol TEXT NOT NULL,
+                hedge_price REAL NOT NULL,
+                hedge_size REAL NOT NULL,
+                realized_spread_bps REAL NOT NULL,
+                funding_bps REAL NOT NULL,
+                hedged_at_ms INTEGER NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS position_snapshots (
+                symbol TEXT PRIMARY KEY,
+                amount REAL NOT NULL,
+                side TEXT NOT NULL,
+                checked_at_ms INTEGER NOT NULL
+            );",
+        )
+        .context("Failed to create storage tables")?;
+
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    /// Record a detected maker fill, timestamped in epoch milliseconds
+    pub fn record_fill(&self, client_order_id: &str, symbol: &str, side: OrderSide, price: f64, size: f64, filled_at_ms: u64) -> Result<()> {
+        self.conn
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO fills (client_order_id, symbol, side, price, size, filled_at_ms) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![client_order_id, symbol, side.as_str(), price, size, filled_at_ms as i64],
+            )
+            .context("Failed to insert fill")?;
+        Ok(())
+    }
+
+    /// Record the hedge that closed out a fill, along with the realized spread and funding captured
+    #[allow(clippy::too_many_arguments)]
+    pub fn record_hedge(
+        &self,
+        fill_client_order_id: &str,
+        symbol: &str,
+        hedge_price: f64,
+        hedge_size: f64,
+        realized_spread_bps: f64,
+        funding_bps: f64,
+        hedged_at_ms: u64,
+    ) -> Result<()> {
+        self.conn
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO hedges (fill_client_order_id, symbol, hedge_price, hedge_size, realized_spread_bps, funding_bps, hedged_at_ms)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![fill_client_order_id, symbol, hedge_price, hedge_size, realized_spread_bps, funding_bps, hedged_at_ms as i64],
+            )
+            .context("Failed to insert hedge")?;
+        Ok(())
+    }
+
+    /// Upsert the most recent position snapshot for a symbol
+    pub fn upsert_position_snapshot(&self, symbol: &str, amount: f64, side: &str, checked_at_ms: u64) -> Result<()> {
+        self.conn
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO position_snapshots (symbol, amount, side, checked_at_ms) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(symbol) DO UPDATE SET amount = excluded.amount, side = excluded.side, checked_at_ms = excluded.checked_at_ms",
+                params![symbol, amount, side, checked_at_ms as i64],
+            )
+            .context("Failed to upsert position snapshot")?;
+        Ok(())
+    }
+
+    /// Rehydrate every `client_order_id` that was ever recorded as filled, so
+    /// a restarted bot doesn't re-hedge a fill it already processed
+    pub fn load_processed_fill_ids(&self) -> Result<HashSet<String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare("SELECT DISTINCT client_order_id FROM fills")
+            .context("Failed to prepare processed-fills query")?;
+        let ids = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .context("Failed to query processed fills")?
+            .collect::<rusqlite::Result<HashSet<String>>>()
+            .context("Failed to collect processed fills")?;
+        Ok(ids)
+    }
+
+    /// Rehydrate the last persisted position snapshot for `symbol`, if any
+    pub fn load_last_position_snapshot(&self, symbol: &str) -> Result<Option<PositionSnapshotRow>> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT amount, side, checked_at_ms FROM position_snapshots WHERE symbol = ?1",
+            params![symbol],
+            |row| {
+                Ok(PositionSnapshotRow {
+                    amount: row.get(0)?,
+                    side: row.get(1)?,
+                    checked_at_ms: row.get::<_, i64>(2)? as u64,
+                })
+            },
+        )
+        .optional()
+        .context("Failed to query last position snapshot")
+    }
+
+    /// Backfill query: reconstruct realized PnL for `symbol` from every hedge on record
+    pub fn symbol_pnl(&self, symbol: &str) -> Result<SymbolPnl> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row(
+            "SELECT COUNT(*), COALESCE(AVG(realized_spread_bps), 0.0), COALESCE(SUM(funding_bps), 0.0)
+             FROM hedges WHERE symbol = ?1",
+            params![symbol],
+            |row| {
+                Ok(SymbolPnl {
+                    hedge_count: row.get::<_, i64>(0)? as u64,
+                    avg_realized_spread_bps: row.get(1)?,
+                    total_funding_bps: row.get(2)?,
+                })
+            },
+        )
+        .context("Failed to compute symbol PnL")
+    }
+}
@@ -0,0 +1,205 @@
+/// Per-venue funding-interval clock and the policy window around each tick.
+///
+/// Both legs of the trade are perpetuals, so a funding payment lands on
+/// whichever side of the trade is net long or short at the tick - ignoring
+/// the *timing* of that payment (as opposed to `OpportunityEvaluator`'s
+/// `FundingRates`, which only prices its *magnitude* as an average carry over
+/// `holding_intervals`) can flip a position from profitable to loss-making
+/// right as funding settles. `FundingScheduler` tracks the next funding
+/// timestamp and predicted rate per venue and derives a `FundingWindowPolicy`
+/// from how close `now_ms` is to the nearer of the two.
+///
+/// Intended integration (the maker-quote loop that would consume this isn't
+/// wired up in this tree, nor is any caller of
+/// `OpportunityEvaluator::set_funding_rates`): before evaluating a new
+/// opportunity, the caller checks `policy(now_ms)` and skips quoting
+/// entirely under `SuppressQuoting`, or under `BiasFlatten` only evaluates
+/// the opportunity direction that reduces current inventory. Both venues'
+/// `predicted_rate_bps` should also be folded into
+/// `OpportunityEvaluator::set_funding_rates` ahead of the tick so
+/// `funding_edge_bps` reflects the upcoming rate rather than the last
+/// observed one.
+use std::time::Duration;
+
+/// Which venue a funding schedule/policy decision pertains to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FundingVenue {
+    Pacifica,
+    Hyperliquid,
+}
+
+/// One venue's funding-interval clock: the next funding timestamp and the
+/// rate predicted to apply at that tick. Defaults to a clock that has never
+/// received a reading, which `FundingScheduler::policy` treats as "no
+/// upcoming tick known" rather than "funding is imminent".
+#[derive(Debug, Clone, Copy, Default)]
+struct VenueFundingClock {
+    next_funding_time_ms: u64,
+    predicted_rate_bps: f64,
+}
+
+/// Quoting/hedging policy derived from how close `now_ms` is to the nearer
+/// of the two venues' next funding tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FundingWindowPolicy {
+    /// Outside both venues' pre-funding windows - trade normally
+    Normal,
+    /// Inside the flatten window - keep quoting, but bias toward reducing
+    /// residual inventory rather than growing it
+    BiasFlatten,
+    /// Inside the suppress window - hold off on new maker quotes until the
+    /// funding tick passes
+    SuppressQuoting,
+}
+
+/// Tracks both venues' funding schedules and turns them into a single
+/// `FundingWindowPolicy` the strategy can act on
+pub struct FundingScheduler {
+    pacifica: VenueFundingClock,
+    hyperliquid: VenueFundingClock,
+    /// How long before the nearer funding tick to start biasing toward
+    /// flattening residual inventory
+    flatten_window: Duration,
+    /// How long before the nearer funding tick to suppress new maker quotes
+    /// entirely; expected to be <= `flatten_window`
+    suppress_window: Duration,
+    /// Whether `SuppressQuoting` is ever returned at all - operators can
+    /// disable outright suppression and rely on `BiasFlatten` alone if
+    /// pulling quotes around funding costs too much spread capture
+    suppress_enabled: bool,
+}
+
+impl FundingScheduler {
+    /// Create a scheduler with no funding schedule known for either venue yet
+    /// - `policy` returns `Normal` until `set_pacifica_schedule` or
+    /// `set_hyperliquid_schedule` reports a schedule for at least one venue
+    pub fn new(flatten_window: Duration, suppress_window: Duration, suppress_enabled: bool) -> Self {
+        Self {
+            pacifica: VenueFundingClock::default(),
+            hyperliquid: VenueFundingClock::default(),
+            flatten_window,
+            suppress_window,
+            suppress_enabled,
+        }
+    }
+
+    /// Refresh Pacifica's funding schedule, as new readings arrive from the
+    /// exchange
+    pub fn set_pacifica_schedule(&mut self, next_funding_time_ms: u64, predicted_rate_bps: f64) {
+        self.pacifica = VenueFundingClock { next_funding_time_ms, predicted_rate_bps };
+    }
+
+    /// Refresh Hyperliquid's funding schedule, as new readings arrive from
+    /// the exchange
+    pub fn set_hyperliquid_schedule(&mut self, next_funding_time_ms: u64, predicted_rate_bps: f64) {
+        self.hyperliquid = VenueFundingClock { next_funding_time_ms, predicted_rate_bps };
+    }
+
+    /// The nearer of the two venues' next funding tick, and which venue it
+    /// belongs to; `None` if neither venue has reported a schedule yet
+    pub fn next_funding(&self) -> Option<(FundingVenue, u64)> {
+        match (self.pacifica.next_funding_time_ms, self.hyperliquid.next_funding_time_ms) {
+            (0, 0) => None,
+            (0, hl) => Some((FundingVenue::Hyperliquid, hl)),
+            (pac, 0) => Some((FundingVenue::Pacifica, pac)),
+            (pac, hl) if pac <= hl => Some((FundingVenue::Pacifica, pac)),
+            (_, hl) => Some((FundingVenue::Hyperliquid, hl)),
+        }
+    }
+
+    /// The most recently reported predicted funding rate for `venue`, in bps
+    pub fn predicted_rate_bps(&self, venue: FundingVenue) -> f64 {
+        match venue {
+            FundingVenue::Pacifica => self.pacifica.predicted_rate_bps,
+            FundingVenue::Hyperliquid => self.hyperliquid.predicted_rate_bps,
+        }
+    }
+
+    /// The quoting/hedging policy in effect at `now_ms`, derived from how
+    /// close it is to `next_funding()`. Returns `Normal` if neither venue has
+    /// reported a schedule, or once the nearer tick has already passed (a
+    /// stale reading is treated the same as no reading, rather than as a
+    /// permanently imminent tick).
+    pub fn policy(&self, now_ms: u64) -> FundingWindowPolicy {
+        let Some((_, next_funding_time_ms)) = self.next_funding() else {
+            return FundingWindowPolicy::Normal;
+        };
+        if next_funding_time_ms <= now_ms {
+            return FundingWindowPolicy::Normal;
+        }
+
+        let until_funding = Duration::from_millis(next_funding_time_ms - now_ms);
+        if self.suppress_enabled && until_funding <= self.suppress_window {
+            FundingWindowPolicy::SuppressQuoting
+        } else if until_funding <= self.flatten_window {
+            FundingWindowPolicy::BiasFlatten
+        } else {
+            FundingWindowPolicy::Normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_schedule_is_normal() {
+        let scheduler = FundingScheduler::new(Duration::from_secs(300), Duration::from_secs(60), true);
+        assert_eq!(scheduler.policy(1_000_000), FundingWindowPolicy::Normal);
+        assert!(scheduler.next_funding().is_none());
+    }
+
+    #[test]
+    fn test_outside_window_is_normal() {
+        let mut scheduler = FundingScheduler::new(Duration::from_secs(300), Duration::from_secs(60), true);
+        scheduler.set_hyperliquid_schedule(1_000_000 + 600_000, 2.5);
+        assert_eq!(scheduler.policy(1_000_000), FundingWindowPolicy::Normal);
+    }
+
+    #[test]
+    fn test_flatten_window_biases_without_suppressing() {
+        let mut scheduler = FundingScheduler::new(Duration::from_secs(300), Duration::from_secs(60), true);
+        scheduler.set_pacifica_schedule(1_000_000 + 200_000, 1.0);
+        assert_eq!(scheduler.policy(1_000_000), FundingWindowPolicy::BiasFlatten);
+    }
+
+    #[test]
+    fn test_suppress_window_suppresses_quoting() {
+        let mut scheduler = FundingScheduler::new(Duration::from_secs(300), Duration::from_secs(60), true);
+        scheduler.set_pacifica_schedule(1_000_000 + 30_000, 1.0);
+        assert_eq!(scheduler.policy(1_000_000), FundingWindowPolicy::SuppressQuoting);
+    }
+
+    #[test]
+    fn test_suppress_disabled_falls_back_to_bias_flatten() {
+        let mut scheduler = FundingScheduler::new(Duration::from_secs(300), Duration::from_secs(60), false);
+        scheduler.set_pacifica_schedule(1_000_000 + 30_000, 1.0);
+        assert_eq!(scheduler.policy(1_000_000), FundingWindowPolicy::BiasFlatten);
+    }
+
+    #[test]
+    fn test_nearer_venue_tick_wins() {
+        let mut scheduler = FundingScheduler::new(Duration::from_secs(300), Duration::from_secs(60), true);
+        scheduler.set_pacifica_schedule(1_000_000 + 600_000, 1.0);
+        scheduler.set_hyperliquid_schedule(1_000_000 + 30_000, -2.0);
+        assert_eq!(scheduler.next_funding(), Some((FundingVenue::Hyperliquid, 1_000_030_000)));
+        assert_eq!(scheduler.policy(1_000_000), FundingWindowPolicy::SuppressQuoting);
+    }
+
+    #[test]
+    fn test_passed_tick_treated_as_normal() {
+        let mut scheduler = FundingScheduler::new(Duration::from_secs(300), Duration::from_secs(60), true);
+        scheduler.set_pacifica_schedule(1_000_000 - 1, 1.0);
+        assert_eq!(scheduler.policy(1_000_000), FundingWindowPolicy::Normal);
+    }
+
+    #[test]
+    fn test_predicted_rate_per_venue() {
+        let mut scheduler = FundingScheduler::new(Duration::from_secs(300), Duration::from_secs(60), true);
+        scheduler.set_pacifica_schedule(2_000_000, 1.25);
+        scheduler.set_hyperliquid_schedule(2_000_000, -0.5);
+        assert!((scheduler.predicted_rate_bps(FundingVenue::Pacifica) - 1.25).abs() < 1e-10);
+        assert!((scheduler.predicted_rate_bps(FundingVenue::Hyperliquid) - (-0.5)).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,7 @@
+/// Strategy layer - opportunity evaluation and funding-aware scheduling
+
+pub mod funding_schedule;
+pub mod opportunity;
+
+pub use funding_schedule::{FundingScheduler, FundingVenue, FundingWindowPolicy};
+pub use opportunity::{Opportunity, OpportunityEvaluator, OrderSide, PriceLevel};
@@ -17,12 +17,20 @@ pub struct Opportunity {
     pub hyperliquid_price: f64,
     /// Order size in base currency
     pub size: f64,
-    /// Calculated profit in basis points
+    /// Calculated profit in basis points, equal to `fee_edge_bps + funding_edge_bps`
     pub initial_profit_bps: f64,
+    /// Portion of `initial_profit_bps` coming from the maker/taker fee spread alone
+    pub fee_edge_bps: f64,
+    /// Portion of `initial_profit_bps` coming from expected funding carry over the
+    /// holding horizon (zero when funding adjustment is disabled)
+    pub funding_edge_bps: f64,
     /// Timestamp when opportunity was evaluated (milliseconds)
     pub timestamp: u64,
 }
 
+/// A single orderbook level as (price, size) in base currency units
+pub type PriceLevel = (f64, f64);
+
 /// Precomputed fee factors to avoid repeated calculations
 #[derive(Debug, Clone, Copy)]
 struct FeeFactors {
@@ -40,6 +48,19 @@ struct FeeFactors {
     sell_denominator: f64,
 }
 
+/// Latest funding-rate snapshot for both venues, used to adjust expected
+/// profit for the carry cost/benefit of holding a hedged position across
+/// funding intervals
+#[derive(Debug, Clone, Copy, Default)]
+struct FundingRates {
+    /// Pacifica funding rate per interval, as a decimal (e.g. 0.0001 for 1 bps)
+    pacifica_rate: f64,
+    /// Hyperliquid funding rate per interval, as a decimal (e.g. 0.0001 for 1 bps)
+    hyperliquid_rate: f64,
+    /// Expected number of funding intervals the position will be held for
+    holding_intervals: f64,
+}
+
 /// Opportunity evaluator for XEMM strategy
 #[derive(Debug, Clone)]
 pub struct OpportunityEvaluator {
@@ -55,6 +76,15 @@ pub struct OpportunityEvaluator {
     inv_tick_size: f64,
     /// Precomputed fee factors
     fee_factors: FeeFactors,
+    /// Fractional haircut applied to the VWAP hedge price to account for
+    /// book movement between evaluation and execution (e.g. 0.01 = 1%)
+    slippage_buffer: f64,
+    /// Latest funding-rate snapshot used to adjust profit for perpetual carry
+    funding_rates: FundingRates,
+    /// Whether funding carry is folded into the profit calculations at all;
+    /// operators can disable this to fall back to fee-only edge if funding
+    /// feeds are unreliable for a given venue/symbol
+    funding_adjustment_enabled: bool,
 }
 
 impl OpportunityEvaluator {
@@ -65,11 +95,19 @@ impl OpportunityEvaluator {
     /// * `taker_fee_bps` - Hyperliquid taker fee in basis points (e.g., 2.5 = 0.025%)
     /// * `profit_rate_bps` - Target profit in basis points (e.g., 10.0 = 0.1%)
     /// * `pacifica_tick_size` - Minimum price increment on Pacifica
+    /// * `pacifica_funding_rate_bps` - Latest Pacifica funding rate per interval, in bps
+    /// * `hyperliquid_funding_rate_bps` - Latest Hyperliquid funding rate per interval, in bps
+    /// * `holding_intervals` - Expected number of funding intervals the position will be held for
+    /// * `funding_adjustment_enabled` - Whether to fold funding carry into the profit calculations at all
     pub fn new(
         maker_fee_bps: f64,
         taker_fee_bps: f64,
         profit_rate_bps: f64,
         pacifica_tick_size: f64,
+        pacifica_funding_rate_bps: f64,
+        hyperliquid_funding_rate_bps: f64,
+        holding_intervals: f64,
+        funding_adjustment_enabled: bool,
     ) -> Self {
         let maker_fee = maker_fee_bps * 0.0001; // Multiply instead of divide
         let taker_fee = taker_fee_bps * 0.0001;
@@ -91,6 +129,71 @@ impl OpportunityEvaluator {
             pacifica_tick_size,
             inv_tick_size: 1.0 / pacifica_tick_size, // Precompute for faster rounding
             fee_factors,
+            slippage_buffer: 0.0,
+            funding_rates: FundingRates {
+                pacifica_rate: pacifica_funding_rate_bps * 0.0001,
+                hyperliquid_rate: hyperliquid_funding_rate_bps * 0.0001,
+                holding_intervals,
+            },
+            funding_adjustment_enabled,
+        }
+    }
+
+    /// Set the slippage buffer applied to VWAP hedge execution estimates
+    ///
+    /// # Arguments
+    /// * `slippage_buffer` - Fractional haircut (e.g. 0.01 for 1%, mirroring
+    ///   mango-v4's liquidator `SLIPPAGE_BUFFER`) applied against the taker
+    ///   in favor of the maker side, to avoid underestimating hedge cost.
+    pub fn set_slippage_buffer(&mut self, slippage_buffer: f64) {
+        self.slippage_buffer = slippage_buffer;
+    }
+
+    /// Refresh the latest funding-rate snapshot, as new readings arrive over
+    /// the exchange websockets
+    ///
+    /// # Arguments
+    /// * `pacifica_funding_rate_bps` - Latest Pacifica funding rate per interval, in bps
+    /// * `hyperliquid_funding_rate_bps` - Latest Hyperliquid funding rate per interval, in bps
+    /// * `holding_intervals` - Expected number of funding intervals the position will be held for
+    pub fn set_funding_rates(
+        &mut self,
+        pacifica_funding_rate_bps: f64,
+        hyperliquid_funding_rate_bps: f64,
+        holding_intervals: f64,
+    ) {
+        self.funding_rates = FundingRates {
+            pacifica_rate: pacifica_funding_rate_bps * 0.0001,
+            hyperliquid_rate: hyperliquid_funding_rate_bps * 0.0001,
+            holding_intervals,
+        };
+    }
+
+    /// Enable or disable folding funding carry into the profit calculations
+    ///
+    /// When disabled, `net_funding_carry_bps` always returns 0.0 and
+    /// opportunities are evaluated on fee edge alone.
+    pub fn set_funding_adjustment_enabled(&mut self, enabled: bool) {
+        self.funding_adjustment_enabled = enabled;
+    }
+
+    /// Net expected funding carry for holding a hedged position, in bps
+    ///
+    /// A BUY-on-Pacifica/SELL-on-Hyperliquid position is long Pacifica and
+    /// short Hyperliquid, so it pays Pacifica's funding rate and receives
+    /// Hyperliquid's; the sign flips for a SELL-on-Pacifica position.
+    /// Returns 0.0 when `funding_adjustment_enabled` is false.
+    #[inline]
+    fn net_funding_carry_bps(&self, direction: OrderSide) -> f64 {
+        if !self.funding_adjustment_enabled {
+            return 0.0;
+        }
+        let carry = (self.funding_rates.hyperliquid_rate - self.funding_rates.pacifica_rate)
+            * self.funding_rates.holding_intervals
+            * 10000.0;
+        match direction {
+            OrderSide::Buy => carry,
+            OrderSide::Sell => -carry,
         }
     }
 
@@ -125,7 +228,9 @@ impl OpportunityEvaluator {
         // Calculate actual profit after rounding (in bps)
         let buy_cost = buy_limit_rounded * self.fee_factors.one_plus_maker;
         let buy_revenue = hl_bid * self.fee_factors.one_minus_taker;
-        let buy_profit_bps = ((buy_revenue - buy_cost) / buy_cost) * 10000.0;
+        let fee_edge_bps = ((buy_revenue - buy_cost) / buy_cost) * 10000.0;
+        let funding_edge_bps = self.net_funding_carry_bps(OrderSide::Buy);
+        let buy_profit_bps = fee_edge_bps + funding_edge_bps;
 
         // Only return if profitable
         if buy_profit_bps > 0.0 {
@@ -135,6 +240,8 @@ impl OpportunityEvaluator {
                 hyperliquid_price: hl_bid,
                 size,
                 initial_profit_bps: buy_profit_bps,
+                fee_edge_bps,
+                funding_edge_bps,
                 timestamp: timestamp_ms,
             })
         } else {
@@ -173,7 +280,9 @@ impl OpportunityEvaluator {
         // Calculate actual profit after rounding (in bps)
         let sell_revenue = sell_limit_rounded * self.fee_factors.one_minus_maker;
         let sell_cost = hl_ask * self.fee_factors.one_plus_taker;
-        let sell_profit_bps = ((sell_revenue - sell_cost) / sell_cost) * 10000.0;
+        let fee_edge_bps = ((sell_revenue - sell_cost) / sell_cost) * 10000.0;
+        let funding_edge_bps = self.net_funding_carry_bps(OrderSide::Sell);
+        let sell_profit_bps = fee_edge_bps + funding_edge_bps;
 
         // Only return if profitable
         if sell_profit_bps > 0.0 {
@@ -183,6 +292,110 @@ impl OpportunityEvaluator {
                 hyperliquid_price: hl_ask,
                 size,
                 initial_profit_bps: sell_profit_bps,
+                fee_edge_bps,
+                funding_edge_bps,
+                timestamp: timestamp_ms,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Evaluate BUY opportunity on Pacifica using full Hyperliquid bid depth
+    ///
+    /// Same as `evaluate_buy_opportunity`, but prices the SELL hedge leg off
+    /// the volume-weighted average price obtained by walking `hl_bid_levels`
+    /// until `size` base units are filled, instead of the top-of-book price.
+    /// This avoids underestimating hedge cost on thin books.
+    ///
+    /// # Arguments
+    /// * `hl_bid_levels` - Hyperliquid bid levels sorted best-to-worst, as `(price, size)`
+    /// * `notional_usd` - Notional order size in USD
+    /// * `timestamp_ms` - Current timestamp in milliseconds
+    ///
+    /// # Returns
+    /// Some(Opportunity) if profitable and the book has enough depth, None otherwise
+    pub fn evaluate_buy_opportunity_vwap(
+        &self,
+        hl_bid_levels: &[PriceLevel],
+        notional_usd: f64,
+        timestamp_ms: u64,
+    ) -> Option<Opportunity> {
+        // Use the top level to size the order the same way the top-of-book path does
+        let (top_bid, _) = *hl_bid_levels.first()?;
+        let buy_limit_price = (top_bid * self.fee_factors.one_minus_taker) / self.fee_factors.buy_denominator;
+        let buy_limit_rounded = self.round_price_down(buy_limit_price);
+        let size = notional_usd / buy_limit_rounded;
+
+        // Walk the book to find the VWAP execution price for `size`, then
+        // haircut it against the maker in case the book moves before we trade
+        let hedge_vwap = vwap_execution_price(hl_bid_levels, size)?;
+        let hedge_vwap = hedge_vwap * (1.0 - self.slippage_buffer);
+
+        let buy_cost = buy_limit_rounded * self.fee_factors.one_plus_maker;
+        let buy_revenue = hedge_vwap * self.fee_factors.one_minus_taker;
+        let fee_edge_bps = ((buy_revenue - buy_cost) / buy_cost) * 10000.0;
+        let funding_edge_bps = self.net_funding_carry_bps(OrderSide::Buy);
+        let buy_profit_bps = fee_edge_bps + funding_edge_bps;
+
+        if buy_profit_bps > 0.0 {
+            Some(Opportunity {
+                direction: OrderSide::Buy,
+                pacifica_price: buy_limit_rounded,
+                hyperliquid_price: hedge_vwap,
+                size,
+                initial_profit_bps: buy_profit_bps,
+                fee_edge_bps,
+                funding_edge_bps,
+                timestamp: timestamp_ms,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Evaluate SELL opportunity on Pacifica using full Hyperliquid ask depth
+    ///
+    /// Same as `evaluate_sell_opportunity`, but prices the BUY hedge leg off
+    /// the volume-weighted average price obtained by walking `hl_ask_levels`
+    /// until `size` base units are filled, instead of the top-of-book price.
+    ///
+    /// # Arguments
+    /// * `hl_ask_levels` - Hyperliquid ask levels sorted best-to-worst, as `(price, size)`
+    /// * `notional_usd` - Notional order size in USD
+    /// * `timestamp_ms` - Current timestamp in milliseconds
+    ///
+    /// # Returns
+    /// Some(Opportunity) if profitable and the book has enough depth, None otherwise
+    pub fn evaluate_sell_opportunity_vwap(
+        &self,
+        hl_ask_levels: &[PriceLevel],
+        notional_usd: f64,
+        timestamp_ms: u64,
+    ) -> Option<Opportunity> {
+        let (top_ask, _) = *hl_ask_levels.first()?;
+        let sell_limit_price = (top_ask * self.fee_factors.one_plus_taker) / self.fee_factors.sell_denominator;
+        let sell_limit_rounded = self.round_price_up(sell_limit_price);
+        let size = notional_usd / sell_limit_rounded;
+
+        let hedge_vwap = vwap_execution_price(hl_ask_levels, size)?;
+        let hedge_vwap = hedge_vwap * (1.0 + self.slippage_buffer);
+
+        let sell_revenue = sell_limit_rounded * self.fee_factors.one_minus_maker;
+        let sell_cost = hedge_vwap * self.fee_factors.one_plus_taker;
+        let fee_edge_bps = ((sell_revenue - sell_cost) / sell_cost) * 10000.0;
+        let funding_edge_bps = self.net_funding_carry_bps(OrderSide::Sell);
+        let sell_profit_bps = fee_edge_bps + funding_edge_bps;
+
+        if sell_profit_bps > 0.0 {
+            Some(Opportunity {
+                direction: OrderSide::Sell,
+                pacifica_price: sell_limit_rounded,
+                hyperliquid_price: hedge_vwap,
+                size,
+                initial_profit_bps: sell_profit_bps,
+                fee_edge_bps,
+                funding_edge_bps,
                 timestamp: timestamp_ms,
             })
         } else {
@@ -241,13 +454,53 @@ impl OpportunityEvaluator {
                 // BUY on Pacifica (at pacifica_price) → SELL on Hyperliquid (at current_hl_bid)
                 let buy_cost = pacifica_price * self.fee_factors.one_plus_maker;
                 let buy_revenue = current_hl_bid * self.fee_factors.one_minus_taker;
-                ((buy_revenue - buy_cost) / buy_cost) * 10000.0
+                ((buy_revenue - buy_cost) / buy_cost) * 10000.0 + self.net_funding_carry_bps(OrderSide::Buy)
             }
             OrderSide::Sell => {
                 // SELL on Pacifica (at pacifica_price) → BUY on Hyperliquid (at current_hl_ask)
                 let sell_revenue = pacifica_price * self.fee_factors.one_minus_maker;
                 let sell_cost = current_hl_ask * self.fee_factors.one_plus_taker;
-                ((sell_revenue - sell_cost) / sell_cost) * 10000.0
+                ((sell_revenue - sell_cost) / sell_cost) * 10000.0 + self.net_funding_carry_bps(OrderSide::Sell)
+            }
+        }
+    }
+
+    /// Recalculate profit using the real hedge-leg depth instead of
+    /// top-of-book, when it's available
+    ///
+    /// Falls back to `current_hl_bid`/`current_hl_ask` (top-of-book) whenever
+    /// `hl_bid_levels`/`hl_ask_levels` don't contain enough cumulative size to
+    /// fill `size` - e.g. a REST-polled-only quote that never carries depth
+    ///
+    /// # Arguments
+    /// * `direction` - Order direction (Buy or Sell)
+    /// * `pacifica_price` - The Pacifica limit price
+    /// * `size` - The fill size the hedge leg needs to clear
+    /// * `hl_bid_levels` - Hyperliquid bid depth, used when `direction` is Buy
+    /// * `hl_ask_levels` - Hyperliquid ask depth, used when `direction` is Sell
+    /// * `current_hl_bid` - Current Hyperliquid best bid (fallback)
+    /// * `current_hl_ask` - Current Hyperliquid best ask (fallback)
+    ///
+    /// # Returns
+    /// Current profit in basis points
+    pub fn recalculate_profit_vwap_raw(
+        &self,
+        direction: OrderSide,
+        pacifica_price: f64,
+        size: f64,
+        hl_bid_levels: &[PriceLevel],
+        hl_ask_levels: &[PriceLevel],
+        current_hl_bid: f64,
+        current_hl_ask: f64,
+    ) -> f64 {
+        match direction {
+            OrderSide::Buy => {
+                let hedge_bid = vwap_execution_price(hl_bid_levels, size).unwrap_or(current_hl_bid);
+                self.recalculate_profit_raw(direction, pacifica_price, hedge_bid, current_hl_ask)
+            }
+            OrderSide::Sell => {
+                let hedge_ask = vwap_execution_price(hl_ask_levels, size).unwrap_or(current_hl_ask);
+                self.recalculate_profit_raw(direction, pacifica_price, current_hl_bid, hedge_ask)
             }
         }
     }
@@ -308,6 +561,36 @@ impl OpportunityEvaluator {
     }
 }
 
+/// Walk orderbook levels accumulating size until `target_size` is filled,
+/// returning the volume-weighted average execution price
+///
+/// Returns `None` if the levels do not contain enough cumulative size to
+/// fill `target_size`, signalling the book is too thin to trade safely.
+pub(crate) fn vwap_execution_price(levels: &[PriceLevel], target_size: f64) -> Option<f64> {
+    if target_size <= 0.0 {
+        return None;
+    }
+
+    let mut remaining = target_size;
+    let mut notional = 0.0;
+
+    for &(price, size) in levels {
+        if remaining <= 0.0 {
+            break;
+        }
+        let take = remaining.min(size);
+        notional += take * price;
+        remaining -= take;
+    }
+
+    if remaining > 0.0 {
+        // Book doesn't have enough depth to fill the requested size
+        None
+    } else {
+        Some(notional / target_size)
+    }
+}
+
 impl OrderSide {
     /// Convert to string representation
     #[inline(always)]
@@ -334,16 +617,58 @@ mod tests {
 
     #[test]
     fn test_fee_factors_precomputation() {
-        let evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01);
+        let evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01, 0.0, 0.0, 1.0, true);
         
         // Verify precomputed factors
         assert!((evaluator.fee_factors.one_plus_maker - 1.0001).abs() < 1e-10);
         assert!((evaluator.fee_factors.one_minus_taker - 0.99975).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_funding_carry_flips_sign_by_direction() {
+        let mut evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01, 0.0, 0.0, 1.0, true);
+
+        // Hyperliquid pays more funding than Pacifica: a BUY (long Pacifica, short
+        // Hyperliquid) position earns the spread, a SELL position pays it.
+        evaluator.set_funding_rates(1.0, 5.0, 2.0);
+
+        let buy_carry = evaluator.net_funding_carry_bps(OrderSide::Buy);
+        let sell_carry = evaluator.net_funding_carry_bps(OrderSide::Sell);
+
+        assert!(buy_carry > 0.0);
+        assert!((buy_carry + sell_carry).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_funding_adjustment_disabled_zeroes_carry() {
+        let mut evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01, 0.0, 0.0, 1.0, false);
+        evaluator.set_funding_rates(1.0, 5.0, 2.0);
+
+        assert_eq!(evaluator.net_funding_carry_bps(OrderSide::Buy), 0.0);
+        assert_eq!(evaluator.net_funding_carry_bps(OrderSide::Sell), 0.0);
+
+        evaluator.set_funding_adjustment_enabled(true);
+        assert!(evaluator.net_funding_carry_bps(OrderSide::Buy) > 0.0);
+    }
+
+    #[test]
+    fn test_vwap_opportunities_include_funding_edge_in_breakdown() {
+        let mut evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01, 0.0, 0.0, 1.0, true);
+        evaluator.set_funding_rates(1.0, 5.0, 2.0);
+        let levels = vec![(100.0, 10.0)];
+
+        let buy_opp = evaluator.evaluate_buy_opportunity_vwap(&levels, 500.0, 0).unwrap();
+        assert!(buy_opp.funding_edge_bps > 0.0);
+        assert!((buy_opp.fee_edge_bps + buy_opp.funding_edge_bps - buy_opp.initial_profit_bps).abs() < 1e-10);
+
+        let sell_opp = evaluator.evaluate_sell_opportunity_vwap(&levels, 500.0, 0).unwrap();
+        assert!(sell_opp.funding_edge_bps < 0.0);
+        assert!((sell_opp.fee_edge_bps + sell_opp.funding_edge_bps - sell_opp.initial_profit_bps).abs() < 1e-10);
+    }
+
     #[test]
     fn test_recalculate_profit_raw_matches_struct_version() {
-        let evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01);
+        let evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01, 0.0, 0.0, 1.0, true);
         
         let opp = Opportunity {
             direction: OrderSide::Buy,
@@ -351,6 +676,8 @@ mod tests {
             hyperliquid_price: 100.5,
             size: 1.0,
             initial_profit_bps: 5.0,
+            fee_edge_bps: 5.0,
+            funding_edge_bps: 0.0,
             timestamp: 0,
         };
         
@@ -367,4 +694,60 @@ mod tests {
         
         assert!((profit_struct - profit_raw).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_vwap_execution_price_walks_levels() {
+        let levels = vec![(100.0, 1.0), (99.5, 2.0), (99.0, 5.0)];
+
+        // Fully within top level
+        assert!((vwap_execution_price(&levels, 1.0).unwrap() - 100.0).abs() < 1e-10);
+
+        // Spans first two levels: (1.0*100.0 + 1.0*99.5) / 2.0
+        let vwap = vwap_execution_price(&levels, 2.0).unwrap();
+        assert!((vwap - 99.75).abs() < 1e-10);
+
+        // Not enough depth
+        assert!(vwap_execution_price(&levels, 100.0).is_none());
+    }
+
+    #[test]
+    fn test_evaluate_buy_opportunity_vwap_applies_slippage_buffer() {
+        let mut evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01, 0.0, 0.0, 1.0, true);
+        let levels = vec![(100.0, 10.0)];
+
+        let without_buffer = evaluator.evaluate_buy_opportunity_vwap(&levels, 500.0, 0).unwrap();
+
+        // 5 bps: small enough that the ~10.5 bps fee edge stays positive
+        evaluator.set_slippage_buffer(0.0005);
+        let with_buffer = evaluator.evaluate_buy_opportunity_vwap(&levels, 500.0, 0).unwrap();
+
+        assert!(with_buffer.hyperliquid_price < without_buffer.hyperliquid_price);
+        assert!(with_buffer.initial_profit_bps < without_buffer.initial_profit_bps);
+    }
+
+    #[test]
+    fn test_recalculate_profit_vwap_raw_walks_depth() {
+        let evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01, 0.0, 0.0, 1.0, true);
+        let bid_levels = vec![(100.3, 1.0), (100.1, 5.0)];
+        let ask_levels = vec![(100.6, 1.0), (100.8, 5.0)];
+
+        // Size spans into the second level, so the VWAP result should differ
+        // from (and be worse than) pricing the whole size at top-of-book
+        let vwap_profit = evaluator.recalculate_profit_vwap_raw(OrderSide::Buy, 100.0, 3.0, &bid_levels, &ask_levels, 100.3, 100.6);
+        let top_of_book_profit = evaluator.recalculate_profit_raw(OrderSide::Buy, 100.0, 100.3, 100.6);
+        assert!(vwap_profit < top_of_book_profit);
+    }
+
+    #[test]
+    fn test_recalculate_profit_vwap_raw_falls_back_to_top_of_book_when_depth_insufficient() {
+        let evaluator = OpportunityEvaluator::new(1.0, 2.5, 10.0, 0.01, 0.0, 0.0, 1.0, true);
+        let hl_bid = 100.3;
+        let hl_ask = 100.6;
+
+        // Empty levels (e.g. a REST-polled-only quote) can't cover any size,
+        // so this should match the plain top-of-book recompute exactly
+        let vwap_profit = evaluator.recalculate_profit_vwap_raw(OrderSide::Sell, 100.0, 3.0, &[], &[], hl_bid, hl_ask);
+        let top_of_book_profit = evaluator.recalculate_profit_raw(OrderSide::Sell, 100.0, hl_bid, hl_ask);
+        assert!((vwap_profit - top_of_book_profit).abs() < 1e-10);
+    }
 }